@@ -0,0 +1,142 @@
+//! Benchmarks of the parse and solve phases of every day on the puzzle inputs.
+//!
+//! Run with `cargo bench`, optionally followed by `--` and filters matched against the benchmark
+//! names, e.g. `cargo bench -- day3 day6/part1`. Every benchmark warms up first, then collects
+//! samples of many iterations each and reports the time of a single iteration.
+
+use advent_of_code_2022 as aoc;
+use aoc::{input, Solution};
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Time spent running a benchmark before measuring it.
+const WARMUP_TIME: Duration = Duration::from_millis(300);
+/// Time every sample should take.
+const SAMPLE_TIME: Duration = Duration::from_millis(20);
+/// Number of samples collected for every benchmark.
+const SAMPLES: usize = 50;
+
+fn main() {
+    let filters: Vec<String> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect();
+    let mut harness = Harness { filters };
+
+    println!(
+        "{:<14} {:>12} {:>12} {:>12} {:>12}",
+        "benchmark", "mean", "median", "std dev", "min"
+    );
+    harness.day::<aoc::day1::Day1>(1);
+    harness.day::<aoc::day2::Day2>(2);
+    harness.day::<aoc::day3::Day3>(3);
+    harness.day::<aoc::day4::Day4>(4);
+    harness.day::<aoc::day5::Day5>(5);
+    harness.day::<aoc::day6::Day6>(6);
+    harness.day::<aoc::day7::Day7>(7);
+}
+
+/// Runs the benchmarks whose names match any of the filters, or all of them without filters.
+struct Harness {
+    filters: Vec<String>,
+}
+
+impl Harness {
+    /// Benchmarks parsing the puzzle input of a day and solving both parts from the parsed input.
+    fn day<S: Solution>(&mut self, day: u32) {
+        let text = match input::read(day) {
+            Ok(text) => text,
+            Err(e) => {
+                println!("day{}: skipped, cannot read the input: {}", day, e);
+                return;
+            }
+        };
+        let parsed = match S::parse(&text) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("day{}: skipped, cannot parse the input: {}", day, e);
+                return;
+            }
+        };
+
+        self.bench(&format!("day{}/parse", day), || S::parse(black_box(&text)));
+        self.bench(&format!("day{}/part1", day), || {
+            S::part1(black_box(&parsed))
+        });
+        self.bench(&format!("day{}/part2", day), || {
+            S::part2(black_box(&parsed))
+        });
+    }
+
+    /// Measures a routine and prints the statistics of a single iteration.
+    fn bench<T>(&mut self, name: &str, mut routine: impl FnMut() -> T) {
+        if !self.filters.is_empty() && !self.filters.iter().any(|f| name.contains(f.as_str())) {
+            return;
+        }
+
+        // Warm up while estimating how long one iteration takes.
+        let start = Instant::now();
+        let mut iterations: u64 = 0;
+        while start.elapsed() < WARMUP_TIME {
+            black_box(routine());
+            iterations += 1;
+        }
+        let per_iteration = start.elapsed().as_secs_f64() / iterations as f64;
+        let iterations = ((SAMPLE_TIME.as_secs_f64() / per_iteration) as u64).max(1);
+
+        let samples: Vec<f64> = (0..SAMPLES)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..iterations {
+                    black_box(routine());
+                }
+                start.elapsed().as_secs_f64() / iterations as f64
+            })
+            .collect();
+
+        let stats = Stats::new(samples);
+        println!(
+            "{:<14} {:>12} {:>12} {:>12} {:>12}",
+            name,
+            format_seconds(stats.mean),
+            format_seconds(stats.median),
+            format_seconds(stats.std_dev),
+            format_seconds(stats.min)
+        );
+    }
+}
+
+/// Statistics of the samples, in seconds per iteration.
+struct Stats {
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+    min: f64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(f64::total_cmp);
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2.0
+        } else {
+            samples[middle]
+        };
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+        Stats {
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            min: samples[0],
+        }
+    }
+}
+
+/// Formats seconds like `12.34µs`.
+fn format_seconds(seconds: f64) -> String {
+    format!("{:.2?}", Duration::from_secs_f64(seconds))
+}
@@ -0,0 +1,231 @@
+//! Known-good answers to the puzzle inputs, recorded in `answers.toml`.
+//!
+//! The file is a small subset of TOML: a `[dayN]` table per day with `part1` and `part2` keys
+//! holding either strings or integers. Comments start with `#`.
+
+use crate::{Error, Part, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the answers file in the project directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Recorded answers keyed by day and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+/// Outcome of checking an answer against the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// The answer matches the recorded one.
+    Pass,
+    /// The answer differs from the recorded one.
+    Fail { expected: String },
+    /// No answer is recorded for the day and part.
+    Missing,
+}
+
+impl Answers {
+    /// Returns the path to the answers file of the project.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE)
+    }
+
+    /// Reads answers from a file.
+    pub fn read(path: &Path) -> Result<Answers> {
+        let text = fs::read_to_string(path)?;
+        Answers::parse(&text)
+    }
+
+    /// Parses answers from the text of an answers file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::answers::Answers;
+    /// use advent_of_code_2022::Part;
+    ///
+    /// let answers = Answers::parse("[day5]\npart1 = \"CMZ\"\npart2 = 42\n").unwrap();
+    /// assert_eq!(answers.get(5, Part::One), Some("CMZ"));
+    /// assert_eq!(answers.get(5, Part::Two), Some("42"));
+    /// ```
+    pub fn parse(text: &str) -> Result<Answers> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let number = i + 1;
+            let content = strip_comment(line).trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = content.strip_prefix('[') {
+                let name = table
+                    .strip_suffix(']')
+                    .ok_or_else(|| Error::parse(number, line, content, "unclosed table header"))?
+                    .trim();
+                let parsed = name
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse::<u32>().ok())
+                    .ok_or_else(|| {
+                        Error::parse(number, line, name, "expected a table like [day1]")
+                    })?;
+                day = Some(parsed);
+                continue;
+            }
+
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| Error::parse(number, line, content, "expected key = value"))?;
+            let (key, value) = (key.trim(), value.trim());
+            let day =
+                day.ok_or_else(|| Error::parse(number, line, key, "key outside of a table"))?;
+            let part = match key {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(Error::parse(number, line, key, "unknown key")),
+            };
+            let value = parse_value(value).ok_or_else(|| {
+                Error::parse(number, line, value, "expected a string or an integer")
+            })?;
+            if answers.insert((day, part), value).is_some() {
+                return Err(Error::parse(number, line, key, "duplicate key"));
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    /// Returns the recorded answer to a part of a day.
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, number(part))).map(String::as_str)
+    }
+
+    /// Checks an answer to a part of a day against the recorded one.
+    pub fn verify(&self, day: u32, part: Part, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+/// Returns the number of a part as used in the answers file.
+fn number(part: Part) -> u32 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Removes a comment from a line, leaving `#` inside strings intact.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses a quoted string or an integer.
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(value) = value.strip_prefix('"') {
+        let value = value.strip_suffix('"')?;
+        if value.contains('"') || value.contains('\\') {
+            return None;
+        }
+        return Some(value.to_string());
+    }
+    value.parse::<i64>().ok().map(|value| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# answers
+[day1]
+part1 = 24000 # example
+part2 = \"45000\"
+
+[day5]
+part1 = \"C#Z\"
+",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("24000"));
+        assert_eq!(answers.get(1, Part::Two), Some("45000"));
+        assert_eq!(answers.get(5, Part::One), Some("C#Z"));
+        assert_eq!(answers.get(5, Part::Two), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Answers::parse("part1 = 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: key outside of a table 'part1'"
+        );
+
+        let error = Answers::parse("[day1]\npart3 = 1").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: unknown key 'part3'");
+
+        let error = Answers::parse("[day1]\npart1 = 1\npart1 = 2").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: duplicate key 'part1'");
+
+        let error = Answers::parse("[week1]").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: expected a table like [day1] 'week1'"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("[day5]\npart1 = \"CMZ\"").unwrap();
+        assert_eq!(answers.verify(5, Part::One, "CMZ"), Verdict::Pass);
+        assert_eq!(
+            answers.verify(5, Part::One, "MCD"),
+            Verdict::Fail {
+                expected: "CMZ".to_string()
+            }
+        );
+        assert_eq!(answers.verify(5, Part::Two, "MCD"), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_project_answers() {
+        let answers = Answers::read(&Answers::default_path()).unwrap();
+        for (day, solver) in crate::SOLUTIONS {
+            let input = crate::input::read(day).unwrap();
+            let run = solver(&input, &Part::ALL).unwrap();
+            for answer in run.answers {
+                // Days scaffolded with `aoc new-day` have no answers until they are solved.
+                if answers.get(day, answer.part).is_none() {
+                    continue;
+                }
+                assert_eq!(
+                    answers.verify(day, answer.part, &answer.value),
+                    Verdict::Pass,
+                    "day {}, part {}",
+                    day,
+                    answer.part
+                );
+            }
+        }
+    }
+}
@@ -1,31 +1,264 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-pub fn parse_file(filename: &str) -> Vec<i32> {
-    let mut v = Vec::new();
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-    let mut sum = 0;
-    for line in reader.lines() {
-        let line = line.unwrap();
+use crate::{Error, Result, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{BufRead, BufReader, Read};
+use std::num::IntErrorKind;
+
+pub mod statistics;
+pub mod stream;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Inventory;
+    type Answer1 = u64;
+    type Answer2 = Result<u64>;
+
+    fn parse(input: &str) -> Result<Inventory> {
+        parse_input(input)
+    }
+
+    fn parse_reader(reader: impl Read) -> Result<Inventory> {
+        parse_reader(BufReader::new(reader))
+    }
+
+    fn part1(inventory: &Inventory) -> u64 {
+        inventory.top_n(1).iter().map(|elf| elf.total()).sum()
+    }
+
+    /// Sums the calories of the top three elves, or returns an error if they overflow.
+    fn part2(inventory: &Inventory) -> Result<u64> {
+        inventory
+            .top_n(3)
+            .iter()
+            .try_fold(0u64, |total, elf| total.checked_add(elf.total()))
+            .ok_or_else(|| Error::Input("calories of the top elves overflow".to_string()))
+    }
+}
+
+/// Food items carried by an elf.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Elf {
+    /// Position of the elf in the input, starting from 0.
+    pub index: usize,
+    /// Calories of every item.
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// Returns the calories of all items, which [`parse_reader`] makes sure fit into a `u64`.
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+}
+
+/// Elves in the order they appear in the input.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+}
+
+impl Inventory {
+    /// Returns every elf.
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// Returns up to `k` elves carrying the most calories, starting with the one carrying most.
+    ///
+    /// Elves carrying the same calories are ordered by their index. Keeps a heap of at most `k`
+    /// elves instead of sorting all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day1;
+    ///
+    /// let inventory = day1::parse_reader("1000\n2000\n\n4000\n\n5000\n".as_bytes()).unwrap();
+    /// let top: Vec<usize> = inventory.top_n(2).iter().map(|elf| elf.index).collect();
+    /// assert_eq!(top, vec![2, 1]);
+    /// ```
+    pub fn top_n(&self, k: usize) -> Vec<&Elf> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for elf in &self.elves {
+            heap.push(Reverse((elf.total(), Reverse(elf.index))));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, Reverse(index)))| &self.elves[index])
+            .collect()
+    }
+}
+
+/// Returns the answer to part 1.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day1;
+///
+/// let input = "1000\n2000\n\n4000\n\n5000\n6000";
+/// assert_eq!(day1::part_1(input).unwrap(), 11000);
+/// ```
+pub fn part_1(input: &str) -> Result<u64> {
+    Ok(Day1::part1(&parse_input(input)?))
+}
+
+/// Returns the answer to part 2.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day1;
+///
+/// let input = "1000\n2000\n\n4000\n\n5000\n6000\n\n10000";
+/// assert_eq!(day1::part_2(input).unwrap(), 25000);
+/// ```
+pub fn part_2(input: &str) -> Result<u64> {
+    Day1::part2(&parse_input(input)?)
+}
+
+/// Parses the items of every elf line by line from a reader, e.g. a file or standard input.
+///
+/// Elves are separated by one or more empty lines. The last elf does not need to be followed by
+/// an empty line, and lines may end with `\r\n`. Returns an error if the calories of an elf
+/// overflow, as [`stream::top_k`] does.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day1;
+///
+/// let inventory = day1::parse_reader("1000\r\n2000\r\n\r\n4000".as_bytes()).unwrap();
+/// let totals: Vec<u64> = inventory.elves().iter().map(|elf| elf.total()).collect();
+/// assert_eq!(totals, vec![3000, 4000]);
+/// ```
+pub fn parse_reader(reader: impl BufRead) -> Result<Inventory> {
+    let mut elves = Vec::new();
+    let mut items = Vec::new();
+    let mut total: u64 = 0;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.strip_suffix('\r').unwrap_or(&line);
         if line.is_empty() {
-            v.push(sum);
-            sum = 0;
+            push_elf(&mut elves, &mut items);
+            total = 0;
             continue;
         }
-        let num = line.parse::<i32>().unwrap();
-        sum += num;
+        let item = line.parse::<u64>().map_err(|e| match e.kind() {
+            IntErrorKind::PosOverflow => Error::parse(i + 1, line, line, "calories overflow"),
+            _ => Error::parse(i + 1, line, line, "invalid calories"),
+        })?;
+        total = total
+            .checked_add(item)
+            .ok_or_else(|| Error::parse(i + 1, line, line, "calories of the elf overflow"))?;
+        items.push(item);
     }
-    v
+    push_elf(&mut elves, &mut items);
+    Ok(Inventory { elves })
 }
 
-pub fn run() {
-    let mut result = parse_file("/Users/ihar/Projects/advent-of-code-2022/input/day1.txt");
-    println!("max: {}", result.iter().max().unwrap().clone());
+/// Adds an elf with the items collected so far, unless there are none.
+fn push_elf(elves: &mut Vec<Elf>, items: &mut Vec<u64>) {
+    if !items.is_empty() {
+        elves.push(Elf {
+            index: elves.len(),
+            items: std::mem::take(items),
+        });
+    }
+}
+
+fn parse_input(input: &str) -> Result<Inventory> {
+    parse_reader(input.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
 
-    result.sort();
-    result.reverse();
+    #[test]
+    fn test_part_1() {
+        let input = input::read_example(1).unwrap();
+        assert_eq!(part_1(&input).unwrap(), 24000);
+    }
 
-    let sum_of_first_three = result[0] + result[1] + result[2];
-    println!("sum of first three: {}", sum_of_first_three);
+    #[test]
+    fn test_part_2() {
+        let input = input::read_example(1).unwrap();
+        assert_eq!(part_2(&input).unwrap(), 45000);
+    }
+
+    #[test]
+    fn test_parse_input_last_group() {
+        let totals = |input| -> Vec<u64> {
+            let inventory = parse_input(input).unwrap();
+            inventory.elves().iter().map(|elf| elf.total()).collect()
+        };
+        assert_eq!(totals("1\n2\n\n3"), vec![3, 3]);
+        assert_eq!(totals("1\n2\n\n3\n"), vec![3, 3]);
+        assert_eq!(totals("1\r\n2\r\n\r\n\r\n3\r\n\r\n"), vec![3, 3]);
+        assert_eq!(totals(""), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_totals_beyond_u32() {
+        let input = "4294967295\n1\n\n4294967295\n\n4294967295";
+        assert_eq!(part_1(input).unwrap(), 4294967296);
+        assert_eq!(part_2(input).unwrap(), 3 * 4294967295 + 1);
+    }
+
+    #[test]
+    fn test_items_beyond_u32() {
+        let input = "4294967296\n\n18446744073709551614\n\n1";
+        let inventory = parse_input(input).unwrap();
+        assert_eq!(Day1::part1(&inventory), 18446744073709551614);
+        let error = Day1::part2(&inventory).unwrap_err();
+        assert_eq!(error.to_string(), "calories of the top elves overflow");
+
+        let error = parse_input("18446744073709551615\n1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: calories of the elf overflow '1'"
+        );
+        let error = parse_input("18446744073709551616").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: calories overflow '18446744073709551616'"
+        );
+    }
+
+    #[test]
+    fn test_top_n() {
+        let inventory = parse_input("5\n\n7\n\n5\n\n1").unwrap();
+        let top = |k| -> Vec<usize> { inventory.top_n(k).iter().map(|elf| elf.index).collect() };
+        assert_eq!(top(0), Vec::<usize>::new());
+        assert_eq!(top(2), vec![1, 0]);
+        assert_eq!(top(10), vec![1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_input_invalid_calories() {
+        let error = parse_input("1000\n\n20x0\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid calories '20x0'"
+        );
+    }
 }
@@ -0,0 +1,258 @@
+//! Statistics of the calories carried by the elves.
+
+use super::Inventory;
+
+/// Percentiles of the totals included in the statistics.
+pub const PERCENTILES: [u32; 5] = [10, 25, 75, 90, 99];
+
+/// Statistics of the calories carried by the elves of an inventory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    /// Number of elves.
+    pub elves: usize,
+    /// Calories carried by all elves, which may exceed those of any single elf.
+    pub total: u128,
+    /// Mean of the calories carried by an elf.
+    pub mean: f64,
+    /// Median of the calories carried by an elf.
+    pub median: f64,
+    /// Calories at each of [`PERCENTILES`], by the nearest-rank method.
+    pub percentiles: Vec<(u32, u64)>,
+    /// Number of elves whose totals fall into ranges of equal width.
+    pub histogram: Vec<Bucket>,
+    /// Every elf ordered by rank, the one carrying the most calories first.
+    pub ranks: Vec<Rank>,
+}
+
+/// Range of totals with the number of elves carrying them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+    /// Smallest total of the range.
+    pub start: u64,
+    /// Largest total of the range, inclusive.
+    pub end: u64,
+    /// Number of elves whose totals are in the range.
+    pub elves: usize,
+}
+
+/// Elf holding a rank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rank {
+    /// Rank, starting from 1 for the elf carrying the most calories.
+    pub rank: usize,
+    /// Index of the elf in the inventory.
+    pub index: usize,
+    /// Number of items carried by the elf.
+    pub items: usize,
+    /// Calories carried by the elf.
+    pub calories: u64,
+}
+
+impl Statistics {
+    /// Computes the statistics of an inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `inventory` - Elves with their items.
+    /// * `buckets` - Maximum number of buckets in the histogram.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day1::{self, statistics::Statistics};
+    ///
+    /// let inventory = day1::parse_reader("1000\n2000\n\n4000\n\n5000\n6000".as_bytes()).unwrap();
+    /// let statistics = Statistics::new(&inventory, 10);
+    /// assert_eq!(statistics.median, 4000.0);
+    /// assert_eq!(statistics.ranks[0].index, 2);
+    /// ```
+    pub fn new(inventory: &Inventory, buckets: usize) -> Statistics {
+        let ranks: Vec<Rank> = inventory
+            .top_n(inventory.elves().len())
+            .into_iter()
+            .enumerate()
+            .map(|(i, elf)| Rank {
+                rank: i + 1,
+                index: elf.index,
+                items: elf.items.len(),
+                calories: elf.total(),
+            })
+            .collect();
+        let mut totals: Vec<u64> = ranks.iter().map(|rank| rank.calories).collect();
+        totals.reverse();
+
+        let elves = totals.len();
+        let total = totals.iter().map(|&total| total as u128).sum();
+        let mean = match elves {
+            0 => 0.0,
+            _ => total as f64 / elves as f64,
+        };
+
+        Statistics {
+            elves,
+            total,
+            mean,
+            median: median(&totals),
+            percentiles: PERCENTILES
+                .iter()
+                .filter_map(|&p| Some((p, percentile(&totals, p)?)))
+                .collect(),
+            histogram: histogram(&totals, buckets),
+            ranks,
+        }
+    }
+
+    /// Returns the statistics as a JSON object.
+    pub fn to_json(&self) -> String {
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(p, calories)| format!("{{\"percentile\":{},\"calories\":{}}}", p, calories))
+            .collect();
+        let histogram: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|bucket| {
+                format!(
+                    "{{\"start\":{},\"end\":{},\"elves\":{}}}",
+                    bucket.start, bucket.end, bucket.elves
+                )
+            })
+            .collect();
+        let ranks: Vec<String> = self
+            .ranks
+            .iter()
+            .map(|rank| {
+                format!(
+                    "{{\"rank\":{},\"elf\":{},\"items\":{},\"calories\":{}}}",
+                    rank.rank, rank.index, rank.items, rank.calories
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"elves\":{},\"total\":{},\"mean\":{},\"median\":{},\"percentiles\":[{}],\"histogram\":[{}],\"ranks\":[{}]}}",
+            self.elves,
+            self.total,
+            self.mean,
+            self.median,
+            percentiles.join(","),
+            histogram.join(","),
+            ranks.join(",")
+        )
+    }
+}
+
+/// Returns the median of sorted totals, or 0 if there are none.
+fn median(totals: &[u64]) -> f64 {
+    let middle = totals.len() / 2;
+    match totals.len() {
+        0 => 0.0,
+        n if n % 2 == 0 => (totals[middle - 1] as f64 + totals[middle] as f64) / 2.0,
+        _ => totals[middle] as f64,
+    }
+}
+
+/// Returns the `p`-th percentile of sorted totals by the nearest-rank method.
+fn percentile(totals: &[u64], p: u32) -> Option<u64> {
+    let rank = (p as usize * totals.len()).div_ceil(100).max(1);
+    totals.get(rank - 1).copied()
+}
+
+/// Splits the range of sorted totals into buckets of equal width and counts the totals in each.
+fn histogram(totals: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (totals.first(), totals.last()) else {
+        return Vec::new();
+    };
+    let span = max - min + 1;
+    let width = span.div_ceil(buckets.max(1) as u64);
+
+    let mut histogram: Vec<Bucket> = (0..span.div_ceil(width))
+        .map(|i| Bucket {
+            start: min + i * width,
+            end: (min + (i + 1) * width - 1).min(max),
+            elves: 0,
+        })
+        .collect();
+    for &total in totals {
+        histogram[((total - min) / width) as usize].elves += 1;
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day1::parse_reader;
+
+    fn statistics(input: &str, buckets: usize) -> Statistics {
+        Statistics::new(&parse_reader(input.as_bytes()).unwrap(), buckets)
+    }
+
+    #[test]
+    fn test_statistics() {
+        let statistics = statistics("1\n2\n\n10\n\n4\n\n7", 3);
+        assert_eq!(statistics.elves, 4);
+        assert_eq!(statistics.total, 24);
+        assert_eq!(statistics.mean, 6.0);
+        assert_eq!(statistics.median, 5.5);
+        assert_eq!(
+            statistics.percentiles,
+            vec![(10, 3), (25, 3), (75, 7), (90, 10), (99, 10)]
+        );
+        assert_eq!(
+            statistics.histogram,
+            vec![
+                Bucket {
+                    start: 3,
+                    end: 5,
+                    elves: 2
+                },
+                Bucket {
+                    start: 6,
+                    end: 8,
+                    elves: 1
+                },
+                Bucket {
+                    start: 9,
+                    end: 10,
+                    elves: 1
+                },
+            ]
+        );
+        assert_eq!(
+            statistics.ranks[0],
+            Rank {
+                rank: 1,
+                index: 1,
+                items: 1,
+                calories: 10
+            }
+        );
+        assert_eq!(statistics.ranks[3].index, 0);
+        assert_eq!(statistics.ranks[3].items, 2);
+    }
+
+    #[test]
+    fn test_statistics_empty() {
+        let statistics = statistics("", 10);
+        assert_eq!(statistics.elves, 0);
+        assert_eq!(statistics.mean, 0.0);
+        assert!(statistics.percentiles.is_empty());
+        assert!(statistics.histogram.is_empty());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            statistics("1\n2\n\n4", 1).to_json(),
+            "{\"elves\":2,\"total\":7,\"mean\":3.5,\"median\":3.5,\
+             \"percentiles\":[{\"percentile\":10,\"calories\":3},{\"percentile\":25,\"calories\":3},\
+             {\"percentile\":75,\"calories\":4},{\"percentile\":90,\"calories\":4},\
+             {\"percentile\":99,\"calories\":4}],\
+             \"histogram\":[{\"start\":3,\"end\":4,\"elves\":2}],\
+             \"ranks\":[{\"rank\":1,\"elf\":1,\"items\":1,\"calories\":4},\
+             {\"rank\":2,\"elf\":0,\"items\":2,\"calories\":3}]}"
+        );
+    }
+}
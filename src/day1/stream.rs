@@ -0,0 +1,199 @@
+//! Streaming the calorie log line by line in constant memory.
+
+use crate::{Error, Result};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+use std::num::IntErrorKind;
+
+/// Elf carrying the most calories up to its rank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leader {
+    /// Position of the elf in the log, starting from 0.
+    pub index: usize,
+    /// Calories carried by the elf.
+    pub calories: u64,
+}
+
+/// What is left of a calorie log after streaming it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// Number of elves in the log.
+    pub elves: usize,
+    /// Number of items in the log.
+    pub items: u64,
+    /// Up to `k` elves carrying the most calories, starting with the one carrying most.
+    pub top: Vec<Leader>,
+}
+
+impl Summary {
+    /// Returns the calories carried by the top elves together, or `None` if they overflow.
+    pub fn top_total(&self) -> Option<u64> {
+        self.top
+            .iter()
+            .try_fold(0u64, |total, leader| total.checked_add(leader.calories))
+    }
+
+    /// Returns the summary as a JSON object.
+    pub fn to_json(&self) -> String {
+        let top: Vec<String> = self
+            .top
+            .iter()
+            .map(|leader| {
+                format!(
+                    "{{\"elf\":{},\"calories\":{}}}",
+                    leader.index, leader.calories
+                )
+            })
+            .collect();
+        format!(
+            "{{\"elves\":{},\"items\":{},\"top\":[{}]}}",
+            self.elves,
+            self.items,
+            top.join(",")
+        )
+    }
+}
+
+/// Reads a calorie log line by line, keeping only the `k` elves carrying the most calories.
+///
+/// Memory does not grow with the size of the log: a single line is kept at a time, along with a
+/// heap of at most `k` elves. Calories are summed as `u64`, and an elf carrying more than fits is
+/// reported as an error at the line where the sum overflows.
+///
+/// # Arguments
+///
+/// * `reader` - Calorie log, e.g. a file or standard input.
+/// * `k` - Number of elves to keep.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day1::stream;
+///
+/// let summary = stream::top_k("1000\n2000\n\n4000\n\n5000\n6000".as_bytes(), 2).unwrap();
+/// assert_eq!(summary.elves, 3);
+/// assert_eq!(summary.top_total(), Some(15000));
+/// ```
+pub fn top_k(mut reader: impl BufRead, k: usize) -> Result<Summary> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    let mut elves = 0;
+    let mut items = 0;
+    let mut calories: Option<u64> = None;
+
+    let mut push = |calories: &mut Option<u64>, elves: &mut usize| {
+        if let Some(total) = calories.take() {
+            heap.push(Reverse((total, Reverse(*elves))));
+            if heap.len() > k {
+                heap.pop();
+            }
+            *elves += 1;
+        }
+    };
+
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        number += 1;
+
+        let line = buffer.trim_end_matches(['\n', '\r']);
+        if line.is_empty() {
+            push(&mut calories, &mut elves);
+            continue;
+        }
+        let item = line.parse::<u64>().map_err(|e| match e.kind() {
+            IntErrorKind::PosOverflow => Error::parse(number, line, line, "calories overflow"),
+            _ => Error::parse(number, line, line, "invalid calories"),
+        })?;
+        let total = calories
+            .unwrap_or(0)
+            .checked_add(item)
+            .ok_or_else(|| Error::parse(number, line, line, "calories of the elf overflow"))?;
+        calories = Some(total);
+        items += 1;
+    }
+    push(&mut calories, &mut elves);
+
+    let top = heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(index)))| Leader { index, calories })
+        .collect();
+    Ok(Summary { elves, items, top })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day1::{parse_reader, Day1};
+    use crate::{input, Solution};
+
+    #[test]
+    fn test_top_k_matches_inventory() {
+        let input = input::read(1).unwrap();
+        let summary = top_k(input.as_bytes(), 3).unwrap();
+        let inventory = parse_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(summary.elves, inventory.elves().len());
+        assert_eq!(summary.top[0].calories, Day1::part1(&inventory));
+        assert_eq!(summary.top_total(), Day1::part2(&inventory).ok());
+    }
+
+    #[test]
+    fn test_top_k_beyond_u32() {
+        let summary = top_k("4294967295\n1\n\n\n\n5\r\n".as_bytes(), 5).unwrap();
+        assert_eq!(
+            summary.top,
+            vec![
+                Leader {
+                    index: 0,
+                    calories: 4294967296
+                },
+                Leader {
+                    index: 1,
+                    calories: 5
+                }
+            ]
+        );
+        assert_eq!(summary.items, 3);
+    }
+
+    #[test]
+    fn test_top_k_agrees_with_inventory_beyond_u32() {
+        let input = "4294967295\n1\n\n8589934592\n\n5";
+        let summary = top_k(input.as_bytes(), 3).unwrap();
+        let inventory = parse_reader(input.as_bytes()).unwrap();
+        let totals: Vec<u64> = inventory.top_n(3).iter().map(|elf| elf.total()).collect();
+        let calories: Vec<u64> = summary.top.iter().map(|leader| leader.calories).collect();
+        assert_eq!(calories, totals);
+        assert_eq!(summary.top_total(), Day1::part2(&inventory).ok());
+    }
+
+    #[test]
+    fn test_top_k_overflow() {
+        let error = top_k("18446744073709551615\n1\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: calories of the elf overflow '1'"
+        );
+
+        let error = top_k("18446744073709551616\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: calories overflow '18446744073709551616'"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let summary = top_k("1\n2\n\n4".as_bytes(), 1).unwrap();
+        assert_eq!(
+            summary.to_json(),
+            "{\"elves\":2,\"items\":3,\"top\":[{\"elf\":1,\"calories\":4}]}"
+        );
+    }
+}
@@ -1,129 +1,206 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::{Result, Solution};
+use encoding::Encoding;
+use rules::{Outcome, Rules, Shape};
 
-enum Shape {
-    Rock,
-    Paper,
-    Scissors,
-}
+pub mod encoding;
+pub mod rules;
+pub mod simulation;
 
-enum Result {
-    Win,
-    Lose,
-    Draw,
-}
+pub struct Day2;
 
-fn parse_rounds(filename: &str) -> Vec<(Shape, Shape)> {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    let result = reader.lines().map(|line| {
-        let line = line.unwrap();
-        let mut shapes = line.split_whitespace();
-        let shape1 = shapes.next().unwrap();
-        let shape2 = shapes.next().unwrap();
-        let shape1 = match shape1 {
-            "A" => Shape::Rock,
-            "B" => Shape::Paper,
-            "C" => Shape::Scissors,
-            _ => panic!("Unknown shape: {}", shape1),
-        };
-        let shape2 = match shape2 {
-            "X" => Shape::Rock,
-            "Y" => Shape::Paper,
-            "Z" => Shape::Scissors,
-            _ => panic!("Unknown shape: {}", shape2),
-        };
-        (shape1, shape2)
-    }).collect();
-    result
+/// Strategy guide read both ways: with the second column as our shape and as the desired result.
+pub struct Guide {
+    rules: Rules,
+    rounds: Vec<(Shape, Shape)>,
+    rounds_part_2: Vec<(Shape, Shape)>,
 }
 
-fn score_round(round: &(Shape, Shape)) -> u32 {
-    let mut score = 0;
+impl Solution for Day2 {
+    type Input = Guide;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    match round {
-        (_, Shape::Rock) => score += 1,
-        (_, Shape::Paper) => score += 2,
-        (_, Shape::Scissors) => score += 3,
+    fn parse(input: &str) -> Result<Guide> {
+        let rules = Rules::classic();
+        Ok(Guide {
+            rounds: Encoding::parse(&rules, encoding::PART_1)?.parse_guide(&rules, input)?,
+            rounds_part_2: Encoding::parse(&rules, encoding::PART_2)?.parse_guide(&rules, input)?,
+            rules,
+        })
     }
 
-    match process_round(round) {
-        Result::Win => score += 6,
-        Result::Draw => score += 3,
-        Result::Lose => score += 0,
+    fn part1(guide: &Guide) -> u32 {
+        total_score(&guide.rules, &guide.rounds)
     }
 
-    score
+    fn part2(guide: &Guide) -> u32 {
+        total_score(&guide.rules, &guide.rounds_part_2)
+    }
 }
 
-fn process_round(round: &(Shape, Shape)) -> Result {
-    match round {
-        (Shape::Rock, Shape::Rock) => Result::Draw,
-        (Shape::Rock, Shape::Paper) => Result::Win,
-        (Shape::Rock, Shape::Scissors) => Result::Lose,
-        (Shape::Paper, Shape::Rock) => Result::Lose,
-        (Shape::Paper, Shape::Paper) => Result::Draw,
-        (Shape::Paper, Shape::Scissors) => Result::Win,
-        (Shape::Scissors, Shape::Rock) => Result::Win,
-        (Shape::Scissors, Shape::Paper) => Result::Lose,
-        (Shape::Scissors, Shape::Scissors) => Result::Draw,
-    }
+/// Returns the answer to part 1.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day2;
+///
+/// let input = "A Y\nB X\nC Z";
+/// assert_eq!(day2::part_1(input).unwrap(), 15);
+/// ```
+pub fn part_1(input: &str) -> Result<u32> {
+    Ok(Day2::part1(&Day2::parse(input)?))
 }
 
-fn parse_rounds_part_2(filename: &str) -> Vec<(Shape, Shape)> {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    let result = reader.lines().map(|line| {
-        let line = line.unwrap();
-        let mut shapes = line.split_whitespace();
-        let shape1 = shapes.next().unwrap();
-        let result = shapes.next().unwrap();
-        let shape1 = match shape1 {
-            "A" => Shape::Rock,
-            "B" => Shape::Paper,
-            "C" => Shape::Scissors,
-            _ => panic!("Unknown shape: {}", shape1),
-        };
-        let result = match result {
-            "X" => Result::Lose,
-            "Y" => Result::Draw,
-            "Z" => Result::Win,
-            _ => panic!("Unknown shape: {}", result),
-        };
-        let pair = convert_result_to_shape(&(shape1, result));
-        pair
-    }).collect();
-    result
+/// Returns the answer to part 2.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day2;
+///
+/// let input = "A Y\nB X\nC Z";
+/// assert_eq!(day2::part_2(input).unwrap(), 12);
+/// ```
+pub fn part_2(input: &str) -> Result<u32> {
+    Ok(Day2::part2(&Day2::parse(input)?))
 }
 
-fn convert_result_to_shape(round: &(Shape, Result)) -> (Shape, Shape) {
-    match round {
-        (Shape::Rock, Result::Lose) => (Shape::Rock, Shape::Scissors),
-        (Shape::Rock, Result::Draw) => (Shape::Rock, Shape::Rock),
-        (Shape::Rock, Result::Win) => (Shape::Rock, Shape::Paper),
-        (Shape::Paper, Result::Lose) => (Shape::Paper, Shape::Rock),
-        (Shape::Paper, Result::Draw) => (Shape::Paper, Shape::Paper),
-        (Shape::Paper, Result::Win) => (Shape::Paper, Shape::Scissors),
-        (Shape::Scissors, Result::Lose) => (Shape::Scissors, Shape::Paper),
-        (Shape::Scissors, Result::Draw) => (Shape::Scissors, Shape::Scissors),
-        (Shape::Scissors, Result::Win) => (Shape::Scissors, Shape::Rock),
-    }
+/// Sums the scores of the rounds, each being the opponent's shape and ours.
+pub(crate) fn total_score(rules: &Rules, rounds: &[(Shape, Shape)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, ours)| rules.score(opponent, ours))
+        .sum()
 }
 
-pub fn run() {
-    let rounds = parse_rounds("/Users/ihar/Projects/advent-of-code-2022/input/day2.txt");
-    let score = rounds.iter()
-        .map(|x| score_round(x))
-        .sum::<u32>();
-    println!("score: {}", score);
+/// Round of a strategy guide with the points it scores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    /// Line of the round in the guide, starting from 1.
+    pub line: usize,
+    pub opponent: Shape,
+    pub ours: Shape,
+    pub outcome: Outcome,
+    /// Points scored for the shape we play.
+    pub shape_points: u32,
+    /// Points scored for the outcome.
+    pub outcome_points: u32,
+    /// Score of this round and all before it.
+    pub total: u32,
 }
 
-pub fn run2() {
-    let rounds = parse_rounds_part_2("/Users/ihar/Projects/advent-of-code-2022/input/day2.txt");
-    let score = rounds.iter()
-        .map(|x| score_round(&x))
-        .sum::<u32>();
-    println!("score: {}", score);
-}
\ No newline at end of file
+/// Explains how every round of a strategy guide is scored.
+///
+/// # Arguments
+///
+/// * `rules` - Rules of the game.
+/// * `rounds` - Opponent's shape and ours in every line of the guide.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day2::{self, encoding::Encoding, rules::Rules};
+///
+/// let rules = Rules::classic();
+/// let encoding = Encoding::parse(&rules, day2::encoding::PART_1).unwrap();
+/// let rounds = encoding.parse_guide(&rules, "A Y\nB X\nC Z").unwrap();
+/// let steps = day2::explain(&rules, &rounds);
+/// assert_eq!(steps[0].shape_points + steps[0].outcome_points, 8);
+/// assert_eq!(steps[2].total, 15);
+/// ```
+pub fn explain(rules: &Rules, rounds: &[(Shape, Shape)]) -> Vec<Step> {
+    let mut total = 0;
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(i, &(opponent, ours))| {
+            let outcome = rules.outcome(opponent, ours);
+            let shape_points = rules.shape_points(ours);
+            let outcome_points = rules.outcome_points().get(outcome);
+            total += shape_points + outcome_points;
+            Step {
+                line: i + 1,
+                opponent,
+                ours,
+                outcome,
+                shape_points,
+                outcome_points,
+                total,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_part_1() {
+        let input = input::read_example(2).unwrap();
+        assert_eq!(part_1(&input).unwrap(), 15);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = input::read_example(2).unwrap();
+        assert_eq!(part_2(&input).unwrap(), 12);
+    }
+
+    fn parse_rounds(spec: &str, input: &str) -> Result<Vec<(Shape, Shape)>> {
+        let rules = Rules::classic();
+        Encoding::parse(&rules, spec)?.parse_guide(&rules, input)
+    }
+
+    #[test]
+    fn test_explain() {
+        let rules = Rules::classic();
+        let input = input::read_example(2).unwrap();
+        let rounds = parse_rounds(encoding::PART_2, &input).unwrap();
+        let steps = explain(&rules, &rounds);
+
+        let rock = rules.shape("Rock").unwrap();
+        assert_eq!(
+            steps[0],
+            Step {
+                line: 1,
+                opponent: rock,
+                ours: rock,
+                outcome: Outcome::Draw,
+                shape_points: 1,
+                outcome_points: 3,
+                total: 4,
+            }
+        );
+        let totals: Vec<u32> = steps.iter().map(|step| step.total).collect();
+        assert_eq!(totals, vec![4, 5, 12]);
+    }
+
+    #[test]
+    fn test_parse_rounds_unknown_shape() {
+        let error = parse_rounds(encoding::PART_1, "A Y\nB Q\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: unknown shape 'Q'");
+
+        let error = parse_rounds(encoding::PART_2, "A Y\nB Q\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: unknown outcome 'Q'");
+    }
+
+    #[test]
+    fn test_parse_rounds_part_2_missing_column() {
+        let error = parse_rounds(encoding::PART_2, "A Y\nC").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: missing second column ''"
+        );
+    }
+}
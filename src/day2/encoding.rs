@@ -0,0 +1,304 @@
+//! Encodings of the columns of a strategy guide.
+//!
+//! An encoding is written as a spec like `A=Rock,B=Paper,C=Scissors;X=lose,Y=draw,Z=win`: the
+//! symbols of the first column mapped to the opponent's shapes, then after `;` the symbols of the
+//! second column mapped to our shapes or to the outcomes we should reach.
+
+use super::rules::{Outcome, Rules, Shape};
+use crate::{Error, Result};
+use std::fmt::Display;
+
+/// Encoding of part 1, with the second column being our shape.
+pub const PART_1: &str = "A=Rock,B=Paper,C=Scissors;X=Rock,Y=Paper,Z=Scissors";
+/// Encoding of part 2, with the second column being the outcome to reach.
+pub const PART_2: &str = "A=Rock,B=Paper,C=Scissors;X=lose,Y=draw,Z=win";
+
+/// What a symbol of the second column stands for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Meaning {
+    /// The shape we play.
+    Shape(Shape),
+    /// The outcome we should reach.
+    Outcome(Outcome),
+}
+
+/// Symbols of both columns of a strategy guide with what they stand for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Encoding {
+    opponent: Vec<(String, Shape)>,
+    ours: Vec<(String, Meaning)>,
+    spec: String,
+}
+
+impl Encoding {
+    /// Parses an encoding spec, resolving shapes by their names in the rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day2::encoding::{self, Encoding};
+    /// use advent_of_code_2022::day2::rules::Rules;
+    ///
+    /// let rules = Rules::classic();
+    /// let encoding = Encoding::parse(&rules, encoding::PART_2).unwrap();
+    /// let rounds = encoding.parse_guide(&rules, "A Y\nB X\nC Z").unwrap();
+    /// assert_eq!(encoding.score(&rules, &rounds), 12);
+    /// ```
+    pub fn parse(rules: &Rules, spec: &str) -> Result<Encoding> {
+        let (opponent, ours) = spec
+            .split_once(';')
+            .ok_or_else(|| Error::parse(1, spec, spec, "expected two columns separated by ';'"))?;
+
+        let opponent = parse_column(spec, opponent, |name| rules.shape(name), "unknown shape")?;
+        let ours = parse_column(
+            spec,
+            ours,
+            |name| match name {
+                "lose" => Some(Meaning::Outcome(Outcome::Lose)),
+                "draw" => Some(Meaning::Outcome(Outcome::Draw)),
+                "win" => Some(Meaning::Outcome(Outcome::Win)),
+                name => rules.shape(name).map(Meaning::Shape),
+            },
+            "unknown shape or outcome",
+        )?;
+
+        Ok(Encoding {
+            opponent,
+            ours,
+            spec: spec.to_string(),
+        })
+    }
+
+    /// Parses a strategy guide into rounds of the opponent's shape and ours.
+    pub fn parse_guide(&self, rules: &Rules, input: &str) -> Result<Vec<(Shape, Shape)>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (first, second) = split_columns(i + 1, line)?;
+                let opponent = lookup(&self.opponent, first)
+                    .ok_or_else(|| Error::parse(i + 1, line, first, "unknown shape"))?;
+                let ours = match lookup(&self.ours, second) {
+                    Some(Meaning::Shape(shape)) => shape,
+                    Some(Meaning::Outcome(outcome)) => rules.shape_for(opponent, outcome),
+                    None => return Err(Error::parse(i + 1, line, second, self.unknown_symbol())),
+                };
+                Ok((opponent, ours))
+            })
+            .collect()
+    }
+
+    /// Sums the scores of the rounds.
+    pub fn score(&self, rules: &Rules, rounds: &[(Shape, Shape)]) -> u32 {
+        super::total_score(rules, rounds)
+    }
+
+    /// Returns every encoding mapping the symbols of the second column to distinct shapes.
+    ///
+    /// The first column stays as it is. Returns no encodings unless the second column has as
+    /// many symbols as there are shapes.
+    pub fn shape_permutations(&self, rules: &Rules) -> Vec<Encoding> {
+        let shapes: Vec<Shape> = rules.shapes().collect();
+        if shapes.len() != self.ours.len() {
+            return Vec::new();
+        }
+
+        permutations(&shapes)
+            .into_iter()
+            .map(|permutation| {
+                let ours: Vec<(String, Meaning)> = self
+                    .ours
+                    .iter()
+                    .zip(permutation)
+                    .map(|((symbol, _), shape)| (symbol.clone(), Meaning::Shape(shape)))
+                    .collect();
+                let spec = format!(
+                    "{};{}",
+                    format_column(&self.opponent, |&shape| rules.name(shape).to_string()),
+                    format_column(&ours, |meaning| match meaning {
+                        Meaning::Shape(shape) => rules.name(*shape).to_string(),
+                        Meaning::Outcome(outcome) => outcome.to_string(),
+                    })
+                );
+                Encoding {
+                    opponent: self.opponent.clone(),
+                    ours,
+                    spec,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the reason of an error for an unknown symbol in the second column.
+    fn unknown_symbol(&self) -> &'static str {
+        let shapes = self
+            .ours
+            .iter()
+            .filter(|(_, meaning)| matches!(meaning, Meaning::Shape(_)))
+            .count();
+        match shapes {
+            0 => "unknown outcome",
+            n if n == self.ours.len() => "unknown shape",
+            _ => "unknown shape or outcome",
+        }
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.spec)
+    }
+}
+
+/// Parses a column of a spec like `A=Rock,B=Paper,C=Scissors`.
+///
+/// # Arguments
+///
+/// * `spec` - Whole spec, for the columns of errors.
+/// * `column` - Part of the spec with the column.
+/// * `resolve` - Returns what a name stands for.
+/// * `unknown` - Reason of the error for names that cannot be resolved.
+fn parse_column<T>(
+    spec: &str,
+    column: &str,
+    resolve: impl Fn(&str) -> Option<T>,
+    unknown: &str,
+) -> Result<Vec<(String, T)>> {
+    let mut symbols: Vec<(String, T)> = Vec::new();
+    for item in column.split(',') {
+        let (symbol, name) = item
+            .split_once('=')
+            .ok_or_else(|| Error::parse(1, spec, item, "expected symbol=name"))?;
+        let (symbol, name) = (symbol.trim(), name.trim());
+        if symbol.is_empty() || symbol.contains(char::is_whitespace) {
+            return Err(Error::parse(1, spec, symbol, "invalid symbol"));
+        }
+        if symbols.iter().any(|(other, _)| other == symbol) {
+            return Err(Error::parse(1, spec, symbol, "duplicate symbol"));
+        }
+        let value = resolve(name).ok_or_else(|| Error::parse(1, spec, name, unknown))?;
+        symbols.push((symbol.to_string(), value));
+    }
+    Ok(symbols)
+}
+
+/// Formats a column of a spec.
+fn format_column<T>(symbols: &[(String, T)], name: impl Fn(&T) -> String) -> String {
+    symbols
+        .iter()
+        .map(|(symbol, value)| format!("{}={}", symbol, name(value)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Returns what a symbol stands for.
+fn lookup<T: Copy>(symbols: &[(String, T)], symbol: &str) -> Option<T> {
+    symbols
+        .iter()
+        .find(|(other, _)| other == symbol)
+        .map(|(_, value)| *value)
+}
+
+/// Returns every ordering of the items, in lexicographic order of their positions.
+fn permutations<T: Copy>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return vec![Vec::new()];
+    }
+    (0..items.len())
+        .flat_map(|i| {
+            let mut rest = items.to_vec();
+            let first = rest.remove(i);
+            permutations(&rest).into_iter().map(move |mut tail| {
+                tail.insert(0, first);
+                tail
+            })
+        })
+        .collect()
+}
+
+/// Splits a line of the strategy guide into its two columns.
+fn split_columns(number: usize, line: &str) -> Result<(&str, &str)> {
+    let end = &line[line.len()..];
+    let mut columns = line.split_whitespace();
+    let first = columns
+        .next()
+        .ok_or_else(|| Error::parse(number, line, end, "missing opponent's shape"))?;
+    let second = columns
+        .next()
+        .ok_or_else(|| Error::parse(number, line, end, "missing second column"))?;
+    match columns.next() {
+        Some(extra) => Err(Error::parse(number, line, extra, "unexpected column")),
+        None => Ok((first, second)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_parse_spec_errors() {
+        let rules = Rules::classic();
+        let error = Encoding::parse(&rules, "A=Rock").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected two columns separated by ';' 'A=Rock'"
+        );
+
+        let error = Encoding::parse(&rules, "A=Rock;X=Stone").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 10: unknown shape or outcome 'Stone'"
+        );
+
+        let error = Encoding::parse(&rules, "A=Rock,A=Paper;X=win").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 8: duplicate symbol 'A'");
+    }
+
+    #[test]
+    fn test_mixed_encoding() {
+        let rules = Rules::classic();
+        let encoding = Encoding::parse(&rules, "A=Rock,B=Paper,C=Scissors;X=Rock,Y=win").unwrap();
+        let rounds = encoding.parse_guide(&rules, "A X\nB Y\nC X").unwrap();
+        assert_eq!(encoding.score(&rules, &rounds), 4 + 9 + 7);
+
+        let error = encoding.parse_guide(&rules, "A Z").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: unknown shape or outcome 'Z'"
+        );
+    }
+
+    #[test]
+    fn test_shape_permutations() {
+        let rules = Rules::classic();
+        let input = input::read_example(2).unwrap();
+        let encodings = Encoding::parse(&rules, PART_1)
+            .unwrap()
+            .shape_permutations(&rules);
+        assert_eq!(encodings.len(), 6);
+        assert_eq!(encodings[0].to_string(), PART_1);
+
+        let scores: Vec<u32> = encodings
+            .iter()
+            .map(|encoding| encoding.score(&rules, &encoding.parse_guide(&rules, &input).unwrap()))
+            .collect();
+        assert_eq!(scores, vec![15, 6, 15, 15, 15, 24]);
+    }
+
+    #[test]
+    fn test_permutations() {
+        assert_eq!(
+            permutations(&[1, 2, 3]),
+            vec![
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1]
+            ]
+        );
+    }
+}
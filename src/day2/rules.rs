@@ -0,0 +1,255 @@
+//! Rules of cyclic games like Rock Paper Scissors.
+//!
+//! Shapes are ordered in a cycle of odd length, where every shape beats the half of the other
+//! shapes preceding it and loses to the half following it. Rock Paper Scissors is the cycle
+//! `Rock, Paper, Scissors`, and Rock Paper Scissors Lizard Spock is
+//! `Rock, Spock, Paper, Lizard, Scissors`.
+
+use std::fmt::Display;
+
+/// Shape played in a round, as its position in the cycle of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape(usize);
+
+impl Shape {
+    /// Returns the position of the shape in the cycle, starting from 0.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Outcome of a round for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    /// Every outcome from the worst to the best.
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Lose => write!(f, "lose"),
+            Outcome::Draw => write!(f, "draw"),
+            Outcome::Win => write!(f, "win"),
+        }
+    }
+}
+
+/// Points scored for the outcome of a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomePoints {
+    pub lose: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl OutcomePoints {
+    /// Returns the points scored for an outcome.
+    pub fn get(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Lose => self.lose,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+impl Default for OutcomePoints {
+    /// Scores 0 for a loss, 3 for a draw and 6 for a win.
+    fn default() -> Self {
+        OutcomePoints {
+            lose: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+/// Shapes of a cyclic game with the points scored for playing them and for the outcomes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    names: Vec<String>,
+    shape_points: Vec<u32>,
+    outcome_points: OutcomePoints,
+}
+
+impl Rules {
+    /// Creates the rules of a game with shapes in the given cycle order.
+    ///
+    /// Playing a shape scores its position in the cycle, starting from 1, and outcomes score the
+    /// [default](OutcomePoints::default) points. Returns `None` unless there is an odd number of
+    /// at least 3 shapes with distinct names, as otherwise some rounds have no winner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day2::rules::{Outcome, Rules};
+    ///
+    /// let rules = Rules::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap();
+    /// let spock = rules.shape("Spock").unwrap();
+    /// let scissors = rules.shape("Scissors").unwrap();
+    /// assert_eq!(rules.outcome(scissors, spock), Outcome::Win);
+    /// assert_eq!(rules.score(scissors, spock), 2 + 6);
+    /// ```
+    pub fn cyclic(names: &[&str]) -> Option<Rules> {
+        let n = names.len();
+        if n < 3 || n.is_multiple_of(2) {
+            return None;
+        }
+        if (1..n).any(|i| names[..i].contains(&names[i])) {
+            return None;
+        }
+        Some(Rules {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            shape_points: (1..=n as u32).collect(),
+            outcome_points: OutcomePoints::default(),
+        })
+    }
+
+    /// Returns the rules of Rock Paper Scissors.
+    pub fn classic() -> Rules {
+        Rules::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    /// Replaces the points scored for playing every shape, in the cycle order.
+    ///
+    /// Returns `None` if the number of points differs from the number of shapes.
+    pub fn with_shape_points(mut self, points: &[u32]) -> Option<Rules> {
+        if points.len() != self.names.len() {
+            return None;
+        }
+        self.shape_points = points.to_vec();
+        Some(self)
+    }
+
+    /// Replaces the points scored for the outcomes.
+    pub fn with_outcome_points(mut self, points: OutcomePoints) -> Rules {
+        self.outcome_points = points;
+        self
+    }
+
+    /// Returns every shape in the cycle order.
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    /// Returns the shape with the given name.
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|other| other == name).map(Shape)
+    }
+
+    /// Returns the name of a shape.
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    /// Returns the points scored for playing a shape.
+    pub fn shape_points(&self, shape: Shape) -> u32 {
+        self.shape_points[shape.0]
+    }
+
+    /// Returns the points scored for the outcomes.
+    pub fn outcome_points(&self) -> OutcomePoints {
+        self.outcome_points
+    }
+
+    /// Returns the outcome of a round for the player playing `ours` against `opponent`.
+    pub fn outcome(&self, opponent: Shape, ours: Shape) -> Outcome {
+        let n = self.names.len();
+        match (ours.0 + n - opponent.0) % n {
+            0 => Outcome::Draw,
+            distance if distance <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// Returns the shape to play against `opponent` to reach the outcome.
+    ///
+    /// When several shapes reach it, returns the one closest to the opponent's in the cycle.
+    pub fn shape_for(&self, opponent: Shape, outcome: Outcome) -> Shape {
+        let n = self.names.len();
+        match outcome {
+            Outcome::Draw => opponent,
+            Outcome::Win => Shape((opponent.0 + 1) % n),
+            Outcome::Lose => Shape((opponent.0 + n - 1) % n),
+        }
+    }
+
+    /// Returns the score of the player playing `ours` against `opponent`.
+    pub fn score(&self, opponent: Shape, ours: Shape) -> u32 {
+        self.shape_points(ours) + self.outcome_points.get(self.outcome(opponent, ours))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classic() {
+        let rules = Rules::classic();
+        let [rock, paper, scissors] =
+            ["Rock", "Paper", "Scissors"].map(|n| rules.shape(n).unwrap());
+
+        assert_eq!(rules.outcome(rock, paper), Outcome::Win);
+        assert_eq!(rules.outcome(rock, scissors), Outcome::Lose);
+        assert_eq!(rules.outcome(scissors, rock), Outcome::Win);
+        assert_eq!(rules.outcome(paper, paper), Outcome::Draw);
+
+        assert_eq!(rules.shape_for(rock, Outcome::Lose), scissors);
+        assert_eq!(rules.shape_for(scissors, Outcome::Win), rock);
+        assert_eq!(rules.score(rock, paper), 8);
+        assert_eq!(rules.score(paper, rock), 1);
+        assert_eq!(rules.score(scissors, scissors), 6);
+    }
+
+    #[test]
+    fn test_shape_for_every_cycle() {
+        for names in [
+            &["Rock", "Paper", "Scissors"][..],
+            &["Rock", "Spock", "Paper", "Lizard", "Scissors"],
+            &["1", "2", "3", "4", "5", "6", "7"],
+        ] {
+            let rules = Rules::cyclic(names).unwrap();
+            for opponent in rules.shapes() {
+                let wins = rules
+                    .shapes()
+                    .filter(|&ours| rules.outcome(opponent, ours) == Outcome::Win)
+                    .count();
+                assert_eq!(wins, names.len() / 2);
+                for outcome in Outcome::ALL {
+                    let ours = rules.shape_for(opponent, outcome);
+                    assert_eq!(rules.outcome(opponent, ours), outcome);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_cycles() {
+        assert_eq!(Rules::cyclic(&["Rock", "Paper"]), None);
+        assert_eq!(Rules::cyclic(&["A", "B", "C", "D"]), None);
+        assert_eq!(Rules::cyclic(&["A", "B", "A"]), None);
+        assert_eq!(Rules::classic().with_shape_points(&[1, 2]), None);
+    }
+
+    #[test]
+    fn test_custom_points() {
+        let rules = Rules::classic()
+            .with_shape_points(&[0, 0, 0])
+            .unwrap()
+            .with_outcome_points(OutcomePoints {
+                lose: 0,
+                draw: 1,
+                win: 2,
+            });
+        assert_eq!(rules.score(Shape(0), Shape(1)), 2);
+        assert_eq!(rules.score(Shape(0), Shape(0)), 1);
+    }
+}
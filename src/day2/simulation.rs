@@ -0,0 +1,358 @@
+//! Simulation of strategies playing against each other.
+
+use super::rules::{Outcome, Rules, Shape};
+
+/// Way of choosing the shape to play in every round.
+pub trait Strategy {
+    /// Returns the name of the strategy.
+    fn name(&self) -> String;
+
+    /// Returns the shape to play in the next round.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - Rules of the game.
+    /// * `ours` - Shapes played by the strategy so far.
+    /// * `theirs` - Shapes played by the opponent so far.
+    fn play(&mut self, rules: &Rules, ours: &[Shape], theirs: &[Shape]) -> Shape;
+
+    /// Forgets everything learned, before a new match.
+    fn reset(&mut self) {}
+}
+
+/// Plays the shapes of a guide in order, starting over when they run out.
+pub struct Fixed {
+    name: String,
+    shapes: Vec<Shape>,
+}
+
+impl Fixed {
+    /// Creates a strategy playing the shapes in order. Panics if there are no shapes.
+    pub fn new(name: &str, shapes: Vec<Shape>) -> Fixed {
+        assert!(
+            !shapes.is_empty(),
+            "a fixed strategy needs at least one shape"
+        );
+        Fixed {
+            name: name.to_string(),
+            shapes,
+        }
+    }
+}
+
+impl Strategy for Fixed {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn play(&mut self, _rules: &Rules, ours: &[Shape], _theirs: &[Shape]) -> Shape {
+        self.shapes[ours.len() % self.shapes.len()]
+    }
+}
+
+/// Plays shapes uniformly at random, repeatably for the same seed.
+pub struct Random {
+    seed: u64,
+    state: u64,
+}
+
+impl Random {
+    /// Creates a strategy playing random shapes generated from the seed.
+    pub fn new(seed: u64) -> Random {
+        Random { seed, state: seed }
+    }
+
+    /// Returns the next number of the SplitMix64 generator.
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        format!("random({})", self.seed)
+    }
+
+    fn play(&mut self, rules: &Rules, _ours: &[Shape], _theirs: &[Shape]) -> Shape {
+        let shapes = rules.shapes().count() as u64;
+        rules.shapes().nth((self.next() % shapes) as usize).unwrap()
+    }
+
+    fn reset(&mut self) {
+        self.state = self.seed;
+    }
+}
+
+/// Plays the shape beating the one the opponent has played most often so far.
+///
+/// Plays the first shape until the opponent has played anything, and prefers the shape coming
+/// first in the cycle among the most frequent ones.
+#[derive(Default)]
+pub struct Frequency {
+    counts: Vec<usize>,
+}
+
+impl Strategy for Frequency {
+    fn name(&self) -> String {
+        "frequency".to_string()
+    }
+
+    fn play(&mut self, rules: &Rules, _ours: &[Shape], theirs: &[Shape]) -> Shape {
+        if self.counts.is_empty() {
+            self.counts = vec![0; rules.shapes().count()];
+        }
+        if let Some(last) = theirs.last() {
+            self.counts[last.index()] += 1;
+        }
+
+        let most_frequent = rules
+            .shapes()
+            .max_by(|a, b| {
+                self.counts[a.index()]
+                    .cmp(&self.counts[b.index()])
+                    .then(b.cmp(a))
+            })
+            .unwrap();
+        match theirs.is_empty() {
+            true => most_frequent,
+            false => rules.shape_for(most_frequent, Outcome::Win),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.counts.clear();
+    }
+}
+
+/// Plays the shape the opponent played in the previous round, and the first shape at the start.
+pub struct Mirror;
+
+impl Strategy for Mirror {
+    fn name(&self) -> String {
+        "mirror".to_string()
+    }
+
+    fn play(&mut self, rules: &Rules, _ours: &[Shape], theirs: &[Shape]) -> Shape {
+        match theirs.last() {
+            Some(&shape) => shape,
+            None => rules.shapes().next().unwrap(),
+        }
+    }
+}
+
+/// Results of a match from the point of view of the first player.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tally {
+    pub rounds: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// Total score of the first player.
+    pub score: u64,
+    /// Total score of the second player.
+    pub opponent_score: u64,
+}
+
+impl Tally {
+    /// Returns the average score of the first player in a round.
+    pub fn average_score(&self) -> f64 {
+        average(self.score, self.rounds)
+    }
+
+    /// Returns the average score of the second player in a round.
+    pub fn opponent_average_score(&self) -> f64 {
+        average(self.opponent_score, self.rounds)
+    }
+}
+
+/// Returns the average score in a round, or 0 without rounds.
+fn average(score: u64, rounds: usize) -> f64 {
+    match rounds {
+        0 => 0.0,
+        _ => score as f64 / rounds as f64,
+    }
+}
+
+/// Result of a match in a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub player: String,
+    pub opponent: String,
+    pub tally: Tally,
+}
+
+/// Plays a match of the given number of rounds between two strategies, resetting them first.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day2::rules::Rules;
+/// use advent_of_code_2022::day2::simulation::{self, Fixed, Mirror};
+///
+/// let rules = Rules::classic();
+/// let paper = rules.shape("Paper").unwrap();
+/// let tally = simulation::play(&rules, &mut Fixed::new("paper", vec![paper]), &mut Mirror, 10);
+/// assert_eq!((tally.wins, tally.draws, tally.losses), (1, 9, 0));
+/// ```
+pub fn play(
+    rules: &Rules,
+    player: &mut dyn Strategy,
+    opponent: &mut dyn Strategy,
+    rounds: usize,
+) -> Tally {
+    player.reset();
+    opponent.reset();
+
+    let mut ours = Vec::with_capacity(rounds);
+    let mut theirs = Vec::with_capacity(rounds);
+    let mut tally = Tally {
+        rounds,
+        ..Tally::default()
+    };
+    for _ in 0..rounds {
+        let our_shape = player.play(rules, &ours, &theirs);
+        let their_shape = opponent.play(rules, &theirs, &ours);
+        match rules.outcome(their_shape, our_shape) {
+            Outcome::Win => tally.wins += 1,
+            Outcome::Draw => tally.draws += 1,
+            Outcome::Lose => tally.losses += 1,
+        }
+        tally.score += rules.score(their_shape, our_shape) as u64;
+        tally.opponent_score += rules.score(our_shape, their_shape) as u64;
+        ours.push(our_shape);
+        theirs.push(their_shape);
+    }
+    tally
+}
+
+/// Plays a match between every pair of distinct strategies, in both orders.
+pub fn tournament(
+    rules: &Rules,
+    strategies: &mut [Box<dyn Strategy>],
+    rounds: usize,
+) -> Vec<Match> {
+    let mut matches = Vec::new();
+    for i in 0..strategies.len() {
+        for j in 0..strategies.len() {
+            if i == j {
+                continue;
+            }
+            let (player, opponent) = pair(strategies, i, j);
+            matches.push(Match {
+                player: player.name(),
+                opponent: opponent.name(),
+                tally: play(rules, player, opponent, rounds),
+            });
+        }
+    }
+    matches
+}
+
+/// Borrows two distinct strategies mutably.
+fn pair(
+    strategies: &mut [Box<dyn Strategy>],
+    i: usize,
+    j: usize,
+) -> (&mut dyn Strategy, &mut dyn Strategy) {
+    if i < j {
+        let (left, right) = strategies.split_at_mut(j);
+        (left[i].as_mut(), right[0].as_mut())
+    } else {
+        let (left, right) = strategies.split_at_mut(i);
+        (right[0].as_mut(), left[j].as_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_play_scores() {
+        let rules = Rules::classic();
+        let [rock, paper, scissors] =
+            ["Rock", "Paper", "Scissors"].map(|n| rules.shape(n).unwrap());
+        let mut player = Fixed::new("guide", vec![paper, rock, scissors]);
+        let mut opponent = Fixed::new("opponent", vec![rock, paper, scissors]);
+
+        let tally = play(&rules, &mut player, &mut opponent, 3);
+        assert_eq!(
+            tally,
+            Tally {
+                rounds: 3,
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                score: 8 + 1 + 6,
+                opponent_score: 1 + 8 + 6,
+            }
+        );
+        assert_eq!(tally.average_score(), 5.0);
+    }
+
+    #[test]
+    fn test_random_is_repeatable() {
+        let rules = Rules::classic();
+        let shapes = |seed| -> Vec<Shape> {
+            let mut random = Random::new(seed);
+            (0..20).map(|_| random.play(&rules, &[], &[])).collect()
+        };
+        assert_eq!(shapes(7), shapes(7));
+        assert_ne!(shapes(7), shapes(8));
+
+        let mut random = Random::new(7);
+        let first = random.play(&rules, &[], &[]);
+        random.reset();
+        assert_eq!(random.play(&rules, &[], &[]), first);
+    }
+
+    #[test]
+    fn test_frequency_beats_fixed() {
+        let rules = Rules::classic();
+        let rock = rules.shape("Rock").unwrap();
+        let tally = play(
+            &rules,
+            &mut Frequency::default(),
+            &mut Fixed::new("rock", vec![rock]),
+            100,
+        );
+        assert_eq!((tally.wins, tally.draws, tally.losses), (99, 1, 0));
+    }
+
+    #[test]
+    fn test_mirror() {
+        let rules = Rules::classic();
+        let scissors = rules.shape("Scissors").unwrap();
+        let mut mirror = Mirror;
+        assert_eq!(mirror.play(&rules, &[], &[]), rules.shape("Rock").unwrap());
+        assert_eq!(mirror.play(&rules, &[], &[scissors]), scissors);
+    }
+
+    #[test]
+    fn test_tournament() {
+        let rules = Rules::classic();
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Mirror),
+            Box::new(Frequency::default()),
+            Box::new(Random::new(1)),
+        ];
+        let matches = tournament(&rules, &mut strategies, 50);
+        assert_eq!(matches.len(), 6);
+        assert_eq!(matches[0].player, "mirror");
+        assert_eq!(matches[0].opponent, "frequency");
+        for m in &matches {
+            let tally = &m.tally;
+            assert_eq!(tally.wins + tally.draws + tally.losses, 50);
+        }
+
+        let mirrored = matches
+            .iter()
+            .find(|m| m.player == "frequency" && m.opponent == "mirror")
+            .unwrap();
+        assert_eq!(mirrored.tally.wins, matches[0].tally.losses);
+    }
+}
@@ -1,92 +1,399 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::{Error, Result, Solution};
+use priority::Priorities;
+use std::io::{BufRead, BufReader, Read};
 
-pub fn run1() {
-    parse_file("/Users/ihar/Projects/advent-of-code-2022/input/day3_test.txt");
+pub mod priority;
+pub mod validation;
+
+pub struct Day3;
+
+/// Size of the groups of elves in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = Result<u32>;
+
+    fn parse(input: &str) -> Result<Vec<Rucksack>> {
+        Self::parse_reader(input.as_bytes())
+    }
+
+    fn parse_reader(reader: impl Read) -> Result<Vec<Rucksack>> {
+        parse_reader(BufReader::new(reader))
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> u32 {
+        sum_of_priorities(rucksacks)
+    }
+
+    /// Sums the priorities of the badges of every group of [`GROUP_SIZE`] elves, or returns an
+    /// error if a group is incomplete or has no single badge.
+    fn part2(rucksacks: &Vec<Rucksack>) -> Result<u32> {
+        let priorities = Priorities::default();
+        let badges = badges(rucksacks, Grouping::Chunks(GROUP_SIZE), &priorities)?;
+        Ok(badges
+            .into_iter()
+            .map(|badge| priorities.priority(badge).expect("an item"))
+            .sum())
+    }
 }
 
-pub fn run2() {
-    parse_file_2("/Users/ihar/Projects/advent-of-code-2022/input/day3.txt");
+/// Set of item types, stored as a bit per type in the order of the item types of [`Priorities`].
+///
+/// Only the priorities that made a set know which item types its bits stand for, see
+/// [`Priorities::items`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Returns the items in both sets.
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// Returns the items in either set.
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    /// Returns the number of items in the set.
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns whether the set has no items.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
 }
 
-fn determine_priority(a: char) -> i32 {
-    1 + "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ".chars()
-        .position(|x| x == a)
-        .unwrap() as i32
+/// Rucksack with its items split into two compartments of equal size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rucksack {
+    items: String,
+    first: ItemSet,
+    second: ItemSet,
 }
 
-fn parse_file(filename: &str) {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    let result: Vec<i32> = reader.lines().map(|line| {
-        let line = line.unwrap();
-        let mid = line.len() / 2;
-        let a = line.chars().take(mid).collect::<String>();
-        let b = line.chars().skip(mid).collect::<String>();
-        let same_chars = find_same_chars(&a, &b);
-        println!("chars {:?}", same_chars);
-
-        let mut sum = 0;
-        same_chars.iter().for_each(|c| {
-            sum += determine_priority(*c);
-        });
-        sum
-    }).collect();
-
-    let sum: i32 = result.iter().sum();
-
-    println!("result: {:?}", result);
-    println!("sum: {}", sum);
+impl Rucksack {
+    /// Creates a rucksack from its items, or returns `None` if any of them is not an ASCII letter.
+    ///
+    /// Item types are those of [`Priorities::default`], see [`Priorities::rucksack`] for others.
+    pub fn new(items: &str) -> Option<Rucksack> {
+        Priorities::default().rucksack(items)
+    }
+
+    /// Returns the items as they were packed.
+    pub fn items(&self) -> &str {
+        &self.items
+    }
+
+    /// Returns the item types in the first and the second compartment.
+    pub fn compartments(&self) -> (ItemSet, ItemSet) {
+        (self.first, self.second)
+    }
+
+    /// Returns the item types in both compartments.
+    pub fn shared(&self) -> ItemSet {
+        self.first.intersection(self.second)
+    }
+
+    /// Returns the item types in the rucksack.
+    pub fn all(&self) -> ItemSet {
+        self.first.union(self.second)
+    }
+}
+
+/// Way of splitting the rucksacks into groups of elves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    /// Consecutive groups of the given size, every rucksack belonging to exactly one.
+    Chunks(usize),
+    /// Every run of the given number of consecutive rucksacks, overlapping each other.
+    Windows(usize),
+}
+
+/// Group of consecutive rucksacks with the item types common to all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    /// Line of the first rucksack of the group, starting from 1.
+    pub first: usize,
+    /// Line of the last rucksack of the group.
+    pub last: usize,
+    /// Item types found in every rucksack of the group.
+    pub common: ItemSet,
+}
+
+impl Group {
+    /// Returns the badge of the group, which must be its only common item type.
+    ///
+    /// The priorities must be those the rucksacks were read with.
+    pub fn badge(&self, priorities: &Priorities) -> Result<char> {
+        let mut items = priorities.items(self.common);
+        match (items.next(), items.next()) {
+            (Some(badge), None) => Ok(badge),
+            (None, _) => Err(Error::group(self.first, self.last, "no common item")),
+            (Some(_), Some(_)) => Err(Error::group(
+                self.first,
+                self.last,
+                &format!(
+                    "multiple common items '{}'",
+                    priorities.items(self.common).collect::<String>()
+                ),
+            )),
+        }
+    }
 }
 
-fn parse_file_2(filename: &str) {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    let mut groups: Vec<Vec<String>> = Vec::new();
-    let mut v: Vec<String> = Vec::new();
-    for line in reader.lines() {
-        let line = line.unwrap();
-        v.push(line);
-        if v.len() == 3 {
-            groups.push(v.clone());
-            v.clear();
+/// Splits the rucksacks into groups and finds the item types common to each group.
+///
+/// With [`Grouping::Chunks`], returns an error if the rucksacks cannot be split into complete
+/// groups. With [`Grouping::Windows`], returns no groups if there are fewer rucksacks than the
+/// size of a window. Panics if the size of the groups is 0.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day3::priority::Priorities;
+/// use advent_of_code_2022::day3::{self, Grouping, Rucksack};
+///
+/// let priorities = Priorities::default();
+/// let rucksacks = ["abcd", "bcef", "cgbh", "xcyz"].map(|items| Rucksack::new(items).unwrap());
+/// let groups = day3::groups(&rucksacks, Grouping::Windows(3)).unwrap();
+/// assert_eq!(priorities.items(groups[0].common).collect::<String>(), "bc");
+/// assert_eq!(groups[1].badge(&priorities).unwrap(), 'c');
+/// assert!(day3::groups(&rucksacks, Grouping::Chunks(3)).is_err());
+/// ```
+pub fn groups(rucksacks: &[Rucksack], grouping: Grouping) -> Result<Vec<Group>> {
+    let (size, step) = match grouping {
+        Grouping::Chunks(size) => (size, size),
+        Grouping::Windows(size) => (size, 1),
+    };
+    assert!(size > 0, "groups need at least one rucksack");
+    if let Grouping::Chunks(_) = grouping {
+        let rest = rucksacks.len() % size;
+        if rest != 0 {
+            let first = rucksacks.len() - rest + 1;
+            return Err(Error::group(
+                first,
+                rucksacks.len(),
+                &format!("incomplete group of {} out of {} rucksacks", rest, size),
+            ));
         }
     }
 
-    let result: i32 = groups.iter().map(|group| {
-        let badge = find_same_char_in_group(&group);
-        determine_priority(badge)
-    }).sum();
+    Ok((0..rucksacks.len().saturating_sub(size - 1))
+        .step_by(step)
+        .map(|start| Group {
+            first: start + 1,
+            last: start + size,
+            common: rucksacks[start..start + size]
+                .iter()
+                .map(Rucksack::all)
+                .reduce(ItemSet::intersection)
+                .unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Returns the badge of every group, the only item type common to all of its rucksacks.
+///
+/// Returns an error if the rucksacks cannot be split into complete groups, or if any group has
+/// no common item type or more than one. The priorities must be those the rucksacks were read
+/// with.
+pub fn badges(
+    rucksacks: &[Rucksack],
+    grouping: Grouping,
+    priorities: &Priorities,
+) -> Result<Vec<char>> {
+    groups(rucksacks, grouping)?
+        .iter()
+        .map(|group| group.badge(priorities))
+        .collect()
+}
+
+/// Returns the answer to part 1.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day3;
+///
+/// let input = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg";
+/// assert_eq!(day3::part_1(input).unwrap(), 96);
+/// ```
+pub fn part_1(input: &str) -> Result<u32> {
+    Ok(Day3::part1(&Day3::parse(input)?))
+}
+
+/// Returns the answer to part 2.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day3;
+///
+/// let input = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg";
+/// assert_eq!(day3::part_2(input).unwrap(), 18);
+/// ```
+pub fn part_2(input: &str) -> Result<u32> {
+    Day3::part2(&Day3::parse(input)?)
+}
+
+/// Item types in the order of their priorities.
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
-    println!("result: {}", result);
+/// Reads rucksacks line by line from a reader, e.g. a file or standard input.
+///
+/// Stops at the first invalid item or rucksack with an odd number of items. See
+/// [`validation::check`] for every problem of an input.
+pub fn parse_reader(reader: impl BufRead) -> Result<Vec<Rucksack>> {
+    parse_with(reader, &Priorities::default())
 }
 
-fn find_same_char_in_group(v: &Vec<String>) -> char {
-    for ac in v[0].chars() {
-        for bc in v[1].chars() {
-            if ac == bc {
-                for cc in v[2].chars() {
-                    if ac == cc {
-                        return ac;
-                    }
+/// Reads rucksacks line by line from a reader, with the item types of custom priorities.
+pub fn parse_with(reader: impl BufRead, priorities: &Priorities) -> Result<Vec<Rucksack>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = line?;
+            match line
+                .char_indices()
+                .find(|&(_, c)| priorities.index(c).is_none())
+            {
+                Some((position, c)) => Err(Error::parse(
+                    i + 1,
+                    &line,
+                    &line[position..position + c.len_utf8()],
+                    "invalid item",
+                )),
+                None if !line.chars().count().is_multiple_of(2) => {
+                    Err(Error::parse(i + 1, &line, &line, "odd number of items"))
                 }
+                None => Ok(priorities.rucksack(&line).expect("only items")),
             }
-        }
-    }
-    ' '
+        })
+        .collect()
 }
 
-fn find_same_chars(a: &str, b: &str) -> Vec<char> {
-    let mut result: Vec<char> = Vec::new();
-    for ac in a.chars() {
-        for bc in b.chars() {
-            if ac == bc {
-                result.push(ac);
-            }
-        }
+/// Sums the priorities of the item types found in both compartments of every rucksack.
+fn sum_of_priorities(rucksacks: &[Rucksack]) -> u32 {
+    let priorities = Priorities::default();
+    rucksacks
+        .iter()
+        .map(|rucksack| priorities.total(rucksack.shared()))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_part_1() {
+        let input = input::read_example(3).unwrap();
+        assert_eq!(part_1(&input).unwrap(), 157);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = input::read_example(3).unwrap();
+        assert_eq!(part_2(&input).unwrap(), 70);
     }
-    result.dedup();
-    result
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_invalid_item() {
+        let error = Day3::parse("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNq RjqzjGDLGL").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 7: invalid item ' '");
+
+        let error = Day3::parse("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLr").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: odd number of items 'jqHRNqRjqzjGDLGLr'"
+        );
+    }
+
+    fn rucksacks(items: &[&str]) -> Vec<Rucksack> {
+        items
+            .iter()
+            .map(|items| Rucksack::new(items).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_badges() {
+        let priorities = Priorities::default();
+        let rucksacks = rucksacks(&["abc", "cde", "ecf", "xy", "yz"]);
+        assert_eq!(
+            badges(&rucksacks[..3], Grouping::Chunks(3), &priorities).unwrap(),
+            vec!['c']
+        );
+        assert_eq!(
+            badges(&rucksacks[3..], Grouping::Chunks(2), &priorities).unwrap(),
+            vec!['y']
+        );
+        assert_eq!(
+            badges(&rucksacks, Grouping::Chunks(1), &priorities)
+                .unwrap_err()
+                .to_string(),
+            "line 1: multiple common items 'abc'"
+        );
+
+        let error = badges(&rucksacks, Grouping::Windows(3), &priorities).unwrap_err();
+        assert_eq!(error.to_string(), "lines 2-4: no common item");
+        assert_eq!(groups(&rucksacks, Grouping::Windows(2)).unwrap().len(), 4);
+        assert!(groups(&rucksacks, Grouping::Windows(6)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_incomplete_group() {
+        let input = input::read_example(3).unwrap();
+        let input: Vec<&str> = input.lines().take(5).collect();
+        let rucksacks = Day3::parse(&input.join("\n")).unwrap();
+        let error = Day3::part2(&rucksacks).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "lines 4-5: incomplete group of 2 out of 3 rucksacks"
+        );
+        assert_eq!(part_1(&input[..2].join("\n")).unwrap(), 16 + 38);
+    }
+
+    #[test]
+    fn test_shared_items_counted_once() {
+        let rucksack = Rucksack::new("aAbaAcaA").unwrap();
+        let shared = Priorities::default()
+            .items(rucksack.shared())
+            .collect::<String>();
+        assert_eq!(shared, "aA");
+        assert_eq!(sum_of_priorities(&[rucksack]), 1 + 27);
+    }
+
+    #[test]
+    fn test_rucksack_not_ascii() {
+        assert_eq!(Rucksack::new("é"), None);
+        assert_eq!(Rucksack::new("aéb"), None);
+        assert!(Rucksack::new("").unwrap().shared().is_empty());
+    }
+
+    #[test]
+    fn test_item_set() {
+        let priorities = Priorities::default();
+        let a = priorities.item_set("abZ").unwrap();
+        let b = priorities.item_set("bZz").unwrap();
+        assert_eq!(priorities.items(a.union(b)).collect::<String>(), "abzZ");
+        assert_eq!(a.intersection(b).len(), 2);
+        assert_eq!(priorities.item_set("ab1"), None);
+        assert!(ItemSet::default().is_empty());
+    }
+}
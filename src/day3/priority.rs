@@ -0,0 +1,372 @@
+//! Priorities of item types, either the puzzle's letters or custom alphabets and weights.
+//!
+//! An alphabet has at most 64 item types, so that sets of them fit into an [`ItemSet`]. Items are
+//! looked up in constant time: ASCII items in a table, others in a hash map.
+
+use super::{ItemSet, Rucksack};
+use crate::{Error, Result};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Largest number of item types in an alphabet.
+pub const MAX_ITEMS: usize = 64;
+
+/// Marks ASCII characters that are not items in the lookup table.
+const NOT_AN_ITEM: u8 = u8::MAX;
+
+/// Item types with their priorities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Priorities {
+    /// Item types in the order of their bits in an [`ItemSet`].
+    items: Vec<char>,
+    /// Priority of every item type, in the same order.
+    weights: Vec<u32>,
+    /// Position of every ASCII character in `items`, or [`NOT_AN_ITEM`].
+    ascii: [u8; 128],
+    /// Position of every other character in `items`.
+    others: HashMap<char, u8>,
+}
+
+impl Default for Priorities {
+    /// Prioritizes `a` to `z` from 1 to 26, then `A` to `Z` from 27 to 52.
+    fn default() -> Self {
+        Priorities::from_alphabet(super::ITEMS).expect("a valid alphabet")
+    }
+}
+
+impl Priorities {
+    /// Creates priorities from an alphabet, the first item type having priority 1.
+    ///
+    /// Returns an error if an item type is repeated, is whitespace, or if there are more than
+    /// [`MAX_ITEMS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day3::priority::Priorities;
+    ///
+    /// let priorities = Priorities::from_alphabet("αβγδ").unwrap();
+    /// assert_eq!(priorities.priority('γ'), Some(3));
+    /// assert_eq!(priorities.priority('a'), None);
+    /// assert!(Priorities::from_alphabet("abca").is_err());
+    /// ```
+    pub fn from_alphabet(alphabet: &str) -> Result<Priorities> {
+        let mut priorities = Priorities::empty();
+        for (i, (position, item)) in alphabet.char_indices().enumerate() {
+            let text = &alphabet[position..position + item.len_utf8()];
+            priorities
+                .insert(item, i as u32 + 1)
+                .map_err(|reason| Error::parse(1, alphabet, text, reason))?;
+        }
+        Ok(priorities)
+    }
+
+    /// Parses a weight file, with an item type and its priority separated by whitespace on every
+    /// line.
+    ///
+    /// Empty lines and lines starting with `#` are skipped. Item types get their bits in the order
+    /// of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day3::priority::Priorities;
+    ///
+    /// let priorities = Priorities::parse("# vowels are worth more\na 10\nb 1\ne 10\n").unwrap();
+    /// assert_eq!(priorities.priority('e'), Some(10));
+    /// assert_eq!(priorities.len(), 3);
+    /// ```
+    pub fn parse(weights: &str) -> Result<Priorities> {
+        let mut priorities = Priorities::empty();
+        for (i, line) in weights.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = trimmed.split_whitespace();
+            let (Some(item), Some(weight), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(Error::parse(
+                    i + 1,
+                    line,
+                    trimmed,
+                    "expected an item and its priority",
+                ));
+            };
+            let mut chars = item.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                return Err(Error::parse(
+                    i + 1,
+                    line,
+                    item,
+                    "expected a single character",
+                ));
+            };
+            let weight = weight
+                .parse()
+                .map_err(|_| Error::parse(i + 1, line, weight, "invalid priority"))?;
+            priorities
+                .insert(c, weight)
+                .map_err(|reason| Error::parse(i + 1, line, item, reason))?;
+        }
+        Ok(priorities)
+    }
+
+    /// Returns priorities without item types.
+    fn empty() -> Priorities {
+        Priorities {
+            items: Vec::new(),
+            weights: Vec::new(),
+            ascii: [NOT_AN_ITEM; 128],
+            others: HashMap::new(),
+        }
+    }
+
+    /// Adds an item type with its priority, returning the reason if it cannot be added.
+    fn insert(&mut self, item: char, weight: u32) -> std::result::Result<(), &'static str> {
+        if item.is_whitespace() {
+            return Err("invalid item");
+        }
+        if self.index(item).is_some() {
+            return Err("duplicate item");
+        }
+        if self.items.len() == MAX_ITEMS {
+            return Err("too many items");
+        }
+
+        let index = self.items.len() as u8;
+        match item.is_ascii() {
+            true => self.ascii[item as usize] = index,
+            false => {
+                self.others.insert(item, index);
+            }
+        }
+        self.items.push(item);
+        self.weights.push(weight);
+        Ok(())
+    }
+
+    /// Returns the number of item types.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether there are no item types.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the bit of an item type in an [`ItemSet`], or `None` if it is not an item.
+    pub fn index(&self, item: char) -> Option<u32> {
+        let index = match item.is_ascii() {
+            true => Some(self.ascii[item as usize]).filter(|&index| index != NOT_AN_ITEM),
+            false => self.others.get(&item).copied(),
+        };
+        index.map(u32::from)
+    }
+
+    /// Returns the priority of an item type, or `None` if it is not an item.
+    pub fn priority(&self, item: char) -> Option<u32> {
+        self.index(item).map(|index| self.weights[index as usize])
+    }
+
+    /// Returns the set of the items in a string, or `None` if any of them is not an item.
+    pub fn item_set(&self, items: &str) -> Option<ItemSet> {
+        items.chars().try_fold(ItemSet::default(), |set, item| {
+            Some(ItemSet(set.0 | 1 << self.index(item)?))
+        })
+    }
+
+    /// Returns the item types of a set in the order of the alphabet.
+    ///
+    /// The set must have been made by the same priorities, as the bits of a set only stand for
+    /// item types through them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day3::priority::Priorities;
+    ///
+    /// let priorities = Priorities::from_alphabet("äöüß").unwrap();
+    /// let rucksack = priorities.rucksack("ßäöä").unwrap();
+    /// assert_eq!(priorities.items(rucksack.shared()).collect::<String>(), "ä");
+    /// ```
+    pub fn items(&self, set: ItemSet) -> impl Iterator<Item = char> + '_ {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| set.0 & 1 << index != 0)
+            .map(|(_, &item)| item)
+    }
+
+    /// Returns the sum of the priorities of the item types of a set.
+    pub fn total(&self, set: ItemSet) -> u32 {
+        self.items(set)
+            .map(|item| self.priority(item).expect("an item"))
+            .sum()
+    }
+
+    /// Creates a rucksack whose items are in the alphabet, or returns `None` if any is not.
+    pub fn rucksack(&self, items: &str) -> Option<Rucksack> {
+        let (first, second) = split_in_middle(items);
+        Some(Rucksack {
+            items: items.to_string(),
+            first: self.item_set(first)?,
+            second: self.item_set(second)?,
+        })
+    }
+}
+
+/// Splits the items into compartments in the middle of the characters, which may take several
+/// bytes each.
+fn split_in_middle(items: &str) -> (&str, &str) {
+    let middle = items
+        .char_indices()
+        .nth(items.chars().count() / 2)
+        .map_or(items.len(), |(position, _)| position);
+    items.split_at(middle)
+}
+
+/// How much an item type contributed to a sum of priorities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contribution {
+    pub item: char,
+    /// Priority of the item type.
+    pub priority: u32,
+    /// Number of sets the item type was found in.
+    pub count: usize,
+    /// Priority times count.
+    pub total: u64,
+}
+
+/// Sums the priorities of the item types of every set per item type.
+///
+/// Returns the item types found in any set, from the one contributing most, then in the order of
+/// the alphabet.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day3::priority::{self, Priorities};
+///
+/// let priorities = Priorities::default();
+/// let sets = ["ab", "b", "Z"].map(|items| priorities.item_set(items).unwrap());
+/// let contributions = priority::contributions(&priorities, sets);
+/// assert_eq!(contributions[0].item, 'Z');
+/// assert_eq!(contributions[1].total, 4);
+/// ```
+pub fn contributions(
+    priorities: &Priorities,
+    sets: impl IntoIterator<Item = ItemSet>,
+) -> Vec<Contribution> {
+    let mut counts = vec![0; priorities.len()];
+    for set in sets {
+        for (index, count) in counts.iter_mut().enumerate() {
+            if set.0 & 1 << index != 0 {
+                *count += 1;
+            }
+        }
+    }
+
+    let mut contributions: Vec<Contribution> = priorities
+        .items
+        .iter()
+        .zip(&priorities.weights)
+        .zip(counts)
+        .filter(|&(_, count)| count > 0)
+        .map(|((&item, &priority), count)| Contribution {
+            item,
+            priority,
+            count,
+            total: priority as u64 * count as u64,
+        })
+        .collect();
+    contributions.sort_by_key(|contribution| Reverse(contribution.total));
+    contributions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day3::{self, Day3};
+    use crate::{input, Solution};
+
+    #[test]
+    fn test_default_matches_letters() {
+        let priorities = Priorities::default();
+        assert_eq!(priorities.len(), 52);
+        for (i, item) in day3::ITEMS.chars().enumerate() {
+            assert_eq!(priorities.priority(item), Some(i as u32 + 1));
+            assert_eq!(priorities.index(item), Some(i as u32));
+        }
+        assert_eq!(priorities.priority('1'), None);
+        assert_eq!(priorities.priority('é'), None);
+    }
+
+    #[test]
+    fn test_contributions_sum_to_answers() {
+        let input = input::read(3).unwrap();
+        let priorities = Priorities::default();
+        let rucksacks = day3::parse_with(input.as_bytes(), &priorities).unwrap();
+
+        let shared = contributions(&priorities, rucksacks.iter().map(|r| r.shared()));
+        let total: u64 = shared.iter().map(|c| c.total).sum();
+        assert_eq!(total, Day3::part1(&rucksacks) as u64);
+        assert!(shared.windows(2).all(|w| w[0].total >= w[1].total));
+    }
+
+    #[test]
+    fn test_unicode_alphabet() {
+        let priorities = Priorities::from_alphabet("äöüß").unwrap();
+        let rucksack = priorities.rucksack("äößü").unwrap();
+        assert_eq!(priorities.items(rucksack.shared()).count(), 0);
+
+        let rucksack = priorities.rucksack("äöüö").unwrap();
+        let shared = rucksack.shared();
+        assert_eq!(priorities.items(shared).collect::<String>(), "ö");
+        assert_eq!(priorities.total(shared), 2);
+        assert_eq!(priorities.rucksack("äa"), None);
+    }
+
+    #[test]
+    fn test_badges_of_custom_alphabet() {
+        let priorities = Priorities::from_alphabet("äöüß").unwrap();
+        let rucksacks: Vec<Rucksack> = ["äöäü", "ßäää", "üäßä"]
+            .iter()
+            .map(|items| priorities.rucksack(items).unwrap())
+            .collect();
+        let badges = day3::badges(&rucksacks, day3::Grouping::Chunks(3), &priorities).unwrap();
+        assert_eq!(badges, vec!['ä']);
+
+        let mut alphabet: String = day3::ITEMS.to_string();
+        alphabet.push('é');
+        let priorities = Priorities::from_alphabet(&alphabet).unwrap();
+        let rucksacks = day3::parse_with("éaéb\nécéd\néeéf\n".as_bytes(), &priorities).unwrap();
+        let groups = day3::groups(&rucksacks, day3::Grouping::Chunks(3)).unwrap();
+        assert_eq!(groups[0].badge(&priorities).unwrap(), 'é');
+        assert_eq!(priorities.total(groups[0].common), 53);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Priorities::parse("a 1\nb one").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid priority 'one'"
+        );
+        let error = Priorities::parse("a 1\n  a 2").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: duplicate item 'a'");
+        let error = Priorities::parse("ab 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a single character 'ab'"
+        );
+
+        let alphabet: String = (0..65)
+            .map(|i| char::from_u32(0x100 + i).unwrap())
+            .collect();
+        let error = Priorities::from_alphabet(&alphabet).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 65: too many items 'ŀ'");
+    }
+}
@@ -0,0 +1,176 @@
+//! Validation of the rucksacks of a puzzle input, reporting every problem instead of the first.
+
+use super::priority::Priorities;
+use std::fmt::Display;
+
+/// What is wrong with a rucksack.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    /// The rucksack has no items.
+    Empty,
+    /// The items cannot be split into two compartments of equal size.
+    OddLength { items: usize },
+    /// An item is not an item type of the priorities.
+    InvalidItem { column: usize, item: char },
+    /// No item type is found in both compartments.
+    NoSharedItem,
+    /// More than one item type is found in both compartments, in the order of their priorities.
+    MultipleSharedItems(String),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Empty => write!(f, "no items"),
+            Problem::OddLength { items } => write!(f, "odd number of items ({})", items),
+            Problem::InvalidItem { column, item } => {
+                write!(f, "invalid item {:?} at column {}", item, column)
+            }
+            Problem::NoSharedItem => write!(f, "no item in both compartments"),
+            Problem::MultipleSharedItems(items) => {
+                write!(f, "multiple items in both compartments '{}'", items)
+            }
+        }
+    }
+}
+
+/// Problem found at a line of the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    /// Line number, starting from 1.
+    pub line: usize,
+    pub problem: Problem,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.problem)
+    }
+}
+
+/// Checks every rucksack of the input, one per line, and returns the problems in line order.
+///
+/// Items must be ASCII letters, as with [`Priorities::default`]. See [`check_with`] for other
+/// item types.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day3::validation::{self, Problem};
+///
+/// let issues = validation::check("abcA\nabca\nab-ab");
+/// assert_eq!(issues.len(), 3);
+/// assert_eq!(issues[0].problem, Problem::NoSharedItem);
+/// assert_eq!(issues[1].to_string(), "line 3: odd number of items (5)");
+/// assert_eq!(issues[2].to_string(), "line 3: invalid item '-' at column 3");
+/// ```
+pub fn check(input: &str) -> Vec<Issue> {
+    check_with(input, &Priorities::default())
+}
+
+/// Checks every rucksack of the input with the item types of custom priorities.
+///
+/// Every invalid item of a line is reported. The compartments of a rucksack are only compared
+/// when it has an even number of valid items. Empty rucksacks are reported on their own.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day3::priority::Priorities;
+/// use advent_of_code_2022::day3::validation;
+///
+/// let priorities = Priorities::from_alphabet("äöüß").unwrap();
+/// let issues = validation::check_with("äöäü\näbäb", &priorities);
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].to_string(), "line 2: invalid item 'b' at column 2");
+/// ```
+pub fn check_with(input: &str, priorities: &Priorities) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut report = |problem| {
+            issues.push(Issue {
+                line: i + 1,
+                problem,
+            })
+        };
+
+        let items = line.chars().count();
+        if items == 0 {
+            report(Problem::Empty);
+            continue;
+        }
+        let odd = !items.is_multiple_of(2);
+        if odd {
+            report(Problem::OddLength { items });
+        }
+        let mut invalid = false;
+        for (column, item) in line.chars().enumerate() {
+            if priorities.index(item).is_none() {
+                report(Problem::InvalidItem {
+                    column: column + 1,
+                    item,
+                });
+                invalid = true;
+            }
+        }
+        if odd || invalid {
+            continue;
+        }
+
+        let shared = priorities.rucksack(line).expect("only items").shared();
+        match shared.len() {
+            0 => report(Problem::NoSharedItem),
+            1 => {}
+            _ => report(Problem::MultipleSharedItems(
+                priorities.items(shared).collect(),
+            )),
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_puzzle_input_is_valid() {
+        assert_eq!(check(&input::read(3).unwrap()), vec![]);
+        assert_eq!(check(&input::read_example(3).unwrap()), vec![]);
+    }
+
+    #[test]
+    fn test_check_with_custom_priorities() {
+        let priorities = Priorities::from_alphabet("éèa").unwrap();
+        let input = "éaéè\nèaèa\nab";
+        let issues: Vec<String> = check_with(input, &priorities)
+            .iter()
+            .map(Issue::to_string)
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                "line 2: multiple items in both compartments 'èa'",
+                "line 3: invalid item 'b' at column 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let issues = check("aBcaBc\n\nxé1x\r");
+        let problems: Vec<String> = issues.iter().map(Issue::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "line 1: multiple items in both compartments 'acB'",
+                "line 2: no items",
+                "line 3: odd number of items (5)",
+                "line 3: invalid item 'é' at column 2",
+                "line 3: invalid item '1' at column 3",
+                "line 3: invalid item '\\r' at column 5",
+            ]
+        );
+    }
+}
@@ -1,59 +1,172 @@
-use std::fs;
-use std::ops::RangeInclusive;
+use crate::{Error, Result, Solution};
+use interval::{Interval, Relation};
 
-pub fn run1(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let result = part_1(&input);
-    println!("result: {}", result);
+pub mod coverage;
+pub mod interval;
+pub mod overlaps;
+
+pub struct Day4;
+
+/// Section assignments of a group of elves, in the order of the line.
+pub type Group = Vec<Interval>;
+
+impl Solution for Day4 {
+    type Input = Vec<Group>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(groups: &Self::Input) -> u32 {
+        count_groups(groups, |relation| Relation::CONTAINMENT.contains(&relation))
+    }
+
+    fn part2(groups: &Self::Input) -> u32 {
+        count_groups(groups, |relation| !Relation::DISJOINT.contains(&relation))
+    }
 }
 
-pub fn run2(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let result = part_2(&input);
-    println!("result: {}", result);
+/// Separators of the ranges on a line and of the bounds of a range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delimiters {
+    /// Separator of the ranges, e.g. `,` in `2-4,6-8`.
+    pub ranges: String,
+    /// Separator of the bounds of a range, e.g. `-` in `2-4` or `..` in `2..4`.
+    pub bounds: String,
 }
 
-fn part_1(input: &String) -> u32 {
-    input.lines().fold(0, |acc, line| {
-        let pair: Vec<&str> = line.split(',').collect();
-        let range_one = str_to_range(pair[0]);
-        let range_two = str_to_range(pair[1]);
-        if ranges_overlap_fully(&range_one, &range_two) {
-            acc + 1
-        } else {
-            acc
+impl Default for Delimiters {
+    /// Separates ranges with `,` and bounds with `-`, as in the puzzle.
+    fn default() -> Self {
+        Delimiters {
+            ranges: ",".to_string(),
+            bounds: "-".to_string(),
         }
-    })
+    }
 }
 
-fn part_2(input: &String) -> u32 {
-    input.lines().fold(0, |acc, line| {
-        let pair: Vec<&str> = line.split(',').collect();
-        let range_one = str_to_range(pair[0]);
-        let range_two = str_to_range(pair[1]);
-        if overlap(&range_one, &range_two) {
-            acc + 1
-        } else {
-            acc
-        }
-    })
+/// Returns the answer to part 1.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day4;
+///
+/// let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+/// assert_eq!(day4::part_1(input).unwrap(), 2);
+/// ```
+pub fn part_1(input: &str) -> Result<u32> {
+    Ok(Day4::part1(&parse_input(input)?))
+}
+
+/// Returns the answer to part 2.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day4;
+///
+/// let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+/// assert_eq!(day4::part_2(input).unwrap(), 4);
+/// ```
+pub fn part_2(input: &str) -> Result<u32> {
+    Ok(Day4::part2(&parse_input(input)?))
 }
 
-fn ranges_overlap_fully(range_one: &RangeInclusive<u32>, range_two: &RangeInclusive<u32>) -> bool {
-    range_one.contains(&range_two.start()) && range_one.contains(&range_two.end()) ||
-        range_two.contains(&range_one.start()) && range_two.contains(&range_one.end())
+/// Parses every line into a group of section ranges with the delimiters of the puzzle.
+fn parse_input(input: &str) -> Result<Vec<Group>> {
+    parse_with(input, &Delimiters::default())
 }
 
-fn overlap(range_one: &RangeInclusive<u32>, range_two: &RangeInclusive<u32>) -> bool {
-    range_one.contains(&range_two.start()) || range_one.contains(&range_two.end()) ||
-        range_two.contains(&range_one.start()) || range_two.contains(&range_one.end())
+/// Parses every line into a group of at least two section ranges.
+///
+/// Bounds may be negative, even when the bounds are separated by `-`. Spaces around ranges are
+/// ignored.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day4::{self, Delimiters};
+///
+/// let delimiters = Delimiters {
+///     ranges: ";".to_string(),
+///     bounds: "..".to_string(),
+/// };
+/// let groups = day4::parse_with("-5..-2; 0..3; -3..1", &delimiters).unwrap();
+/// assert_eq!(groups[0].len(), 3);
+/// assert_eq!(groups[0][0].start(), -5);
+/// ```
+pub fn parse_with(input: &str, delimiters: &Delimiters) -> Result<Vec<Group>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let group = line
+                .split(delimiters.ranges.as_str())
+                .map(|range| {
+                    let range = range.trim();
+                    str_to_range(range, &delimiters.bounds)
+                        .ok_or_else(|| Error::parse(i + 1, line, range, "invalid range"))
+                })
+                .collect::<Result<Group>>()?;
+            if group.len() < 2 {
+                return Err(Error::parse(
+                    i + 1,
+                    line,
+                    line,
+                    "expected at least two ranges",
+                ));
+            }
+            Ok(group)
+        })
+        .collect()
 }
 
-fn str_to_range(s: &str) -> RangeInclusive<u32> {
-    let pair: Vec<&str> = s.split('-').collect();
-    let start = pair[0].parse::<u32>().unwrap();
-    let end = pair[1].parse::<u32>().unwrap();
-    start..=end
+/// Returns whether any two ranges of the group relate as the predicate requires.
+pub fn any_pair(group: &[Interval], predicate: fn(Relation) -> bool) -> bool {
+    group.iter().enumerate().any(|(i, range_one)| {
+        group[i + 1..]
+            .iter()
+            .any(|range_two| predicate(range_one.relation(*range_two)))
+    })
+}
+
+/// Counts the groups with two ranges relating as the predicate requires.
+fn count_groups(groups: &[Group], predicate: fn(Relation) -> bool) -> u32 {
+    groups
+        .iter()
+        .filter(|group| any_pair(group, predicate))
+        .count() as u32
+}
+
+/// Parses a range with bounds separated by `bounds`, like `2-4` for `-` or `-4..-2` for `..`.
+///
+/// Returns `None` if the range is malformed or its start exceeds its end. The separator is looked
+/// for after the first character, so that a minus sign starting the range belongs to the first
+/// bound.
+fn str_to_range(s: &str, bounds: &str) -> Option<Interval> {
+    if bounds.is_empty() {
+        return None;
+    }
+    s.char_indices()
+        .skip(1)
+        .find_map(|(position, _)| {
+            let end = s[position..].strip_prefix(bounds)?;
+            let start = s[..position].parse::<i64>().ok()?;
+            let end = end.parse::<i64>().ok()?;
+            Some((start, end))
+        })
+        .and_then(|(start, end)| Interval::new(start, end))
 }
 
 #[cfg(test)]
@@ -62,45 +175,76 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let input = String::from("2-4,6-8
+        let input = String::from(
+            "2-4,6-8
 2-3,4-5
 5-7,7-9
 2-8,3-7
 6-6,4-6
-2-6,4-8");
+2-6,4-8",
+        );
 
-        assert_eq!(part_1(&input), 2);
+        assert_eq!(part_1(&input).unwrap(), 2);
     }
 
     #[test]
     fn test_str_to_range() {
-        let range = str_to_range("2-4");
-        assert_eq!(range, 2..=4);
+        let range = str_to_range("2-4", "-").unwrap();
+        assert_eq!(range, Interval::new(2, 4).unwrap());
+
+        assert_eq!(str_to_range("2-", "-"), None);
+        assert_eq!(str_to_range("4-2", "-"), None);
+
+        let range = str_to_range("-4--2", "-").unwrap();
+        assert_eq!(range, Interval::new(-4, -2).unwrap());
+        assert_eq!(str_to_range("-3-5", "-"), Interval::new(-3, 5));
+        assert_eq!(str_to_range("-", "-"), None);
+        assert_eq!(str_to_range("1..+2", ".."), Interval::new(1, 2));
     }
 
     #[test]
-    fn test_ranges_overlap_fully() {
-        let range_one = str_to_range("2-4");
-        let range_two = str_to_range("4-5");
-        assert_eq!(ranges_overlap_fully(&range_one, &range_two), false);
-
-        let range_one = str_to_range("2-4");
-        let range_two = str_to_range("3-3");
-        assert_eq!(ranges_overlap_fully(&range_one, &range_two), true);
+    fn test_groups_of_three() {
+        let input = "1-2,5-6,2-3\n1-1,3-3,5-5\n1-9,3-3,10-12";
+        let groups = parse_input(input).unwrap();
+        assert_eq!(Day4::part1(&groups), 1);
+        assert_eq!(Day4::part2(&groups), 2);
+
+        let error = parse_input("1-2,3-4\n1-2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected at least two ranges '1-2'"
+        );
     }
 
     #[test]
-    fn test_overlap() {
-        let range_one = str_to_range("2-4");
-        let range_two = str_to_range("4-5");
-        assert_eq!(overlap(&range_one, &range_two), true);
-
-        let range_one = str_to_range("2-4");
-        let range_two = str_to_range("3-3");
-        assert_eq!(overlap(&range_one, &range_two), true);
-
-        let range_one = str_to_range("2-4");
-        let range_two = str_to_range("5-7");
-        assert_eq!(overlap(&range_one, &range_two), false);
+    fn test_parse_input_invalid_range() {
+        let error = parse_input("2-4,6-8\n2-3,4_5").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 5: invalid range '4_5'");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_nests() {
+        let range_one = str_to_range("2-4", "-").unwrap();
+        let range_two = str_to_range("4-5", "-").unwrap();
+        assert!(!range_one.nests(range_two));
+
+        let range_one = str_to_range("2-4", "-").unwrap();
+        let range_two = str_to_range("3-3", "-").unwrap();
+        assert!(range_one.nests(range_two));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let range_one = str_to_range("2-4", "-").unwrap();
+        let range_two = str_to_range("4-5", "-").unwrap();
+        assert!(range_one.overlaps(range_two));
+
+        let range_one = str_to_range("2-4", "-").unwrap();
+        let range_two = str_to_range("3-3", "-").unwrap();
+        assert!(range_one.overlaps(range_two));
+
+        let range_one = str_to_range("2-4", "-").unwrap();
+        let range_two = str_to_range("5-7", "-").unwrap();
+        assert!(!range_one.overlaps(range_two));
+    }
+}
@@ -0,0 +1,275 @@
+//! Coverage of the sections by the assignments of every elf.
+
+use super::interval::Interval;
+
+/// Disjoint intervals in increasing order, with at least one section between any two of them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    /// Returns the intervals in increasing order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns the number of sections in the set.
+    pub fn sections(&self) -> u64 {
+        self.intervals
+            .iter()
+            .map(|interval| interval.sections())
+            .sum()
+    }
+
+    /// Returns whether the section is in the set.
+    pub fn contains(&self, section: i64) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end() < section);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(section))
+    }
+
+    /// Returns the intervals between the first and the last section of the set not in the set.
+    pub fn gaps(&self) -> Vec<Interval> {
+        self.intervals
+            .windows(2)
+            .filter_map(|pair| Interval::new(pair[0].end() + 1, pair[1].start() - 1))
+            .collect()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    /// Merges the intervals, joining those that overlap or meet.
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut sorted: Vec<Interval> = iter.into_iter().collect();
+        sorted.sort();
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) => match last.union(interval) {
+                    Some(union) => *last = union,
+                    None => intervals.push(interval),
+                },
+                None => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+/// Sections covered by the same number of assignments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub interval: Interval,
+    /// Number of assignments covering every section of the interval.
+    pub count: usize,
+}
+
+/// Coverage of the sections by a list of assignments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    /// Number of assignments.
+    pub assignments: usize,
+    /// Sections covered by any assignment.
+    pub covered: IntervalSet,
+    /// Covered sections split where the number of assignments covering them changes, in order.
+    pub segments: Vec<Segment>,
+}
+
+impl Coverage {
+    /// Computes the coverage of the assignments by sweeping over their bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day4::coverage::Coverage;
+    /// use advent_of_code_2022::day4::interval::Interval;
+    ///
+    /// let assignments = [(2, 4), (3, 6), (9, 9)].map(|(a, b)| Interval::new(a, b).unwrap());
+    /// let coverage = Coverage::new(assignments);
+    /// assert_eq!(coverage.covered.sections(), 6);
+    /// assert_eq!(coverage.covered.gaps(), vec![Interval::new(7, 8).unwrap()]);
+    /// assert_eq!(coverage.most_covered().unwrap().interval, Interval::new(3, 4).unwrap());
+    /// ```
+    pub fn new(assignments: impl IntoIterator<Item = Interval>) -> Coverage {
+        let assignments: Vec<Interval> = assignments.into_iter().collect();
+
+        // Bounds are widened so that the section after i64::MAX has a place.
+        let mut events: Vec<(i128, isize)> = assignments
+            .iter()
+            .flat_map(|interval| {
+                [
+                    (interval.start() as i128, 1),
+                    (interval.end() as i128 + 1, -1),
+                ]
+            })
+            .collect();
+        events.sort_unstable();
+
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut count = 0;
+        let mut events = events.iter().peekable();
+        while let Some(&(position, change)) = events.next() {
+            count += change;
+            if events.peek().is_some_and(|&&(next, _)| next == position) {
+                continue;
+            }
+            let (Some(&&(next, _)), true) = (events.peek(), count > 0) else {
+                continue;
+            };
+            let interval = Interval::new(position as i64, (next - 1) as i64).expect("sorted");
+            match segments.last_mut() {
+                Some(last)
+                    if last.count == count as usize
+                        && last.interval.end() + 1 == position as i64 =>
+                {
+                    last.interval = last.interval.union(interval).expect("adjacent");
+                }
+                _ => segments.push(Segment {
+                    interval,
+                    count: count as usize,
+                }),
+            }
+        }
+
+        Coverage {
+            assignments: assignments.len(),
+            covered: assignments.into_iter().collect(),
+            segments,
+        }
+    }
+
+    /// Returns the first sections covered by the most assignments, or `None` without assignments.
+    pub fn most_covered(&self) -> Option<Segment> {
+        self.segments
+            .iter()
+            .copied()
+            .reduce(|most, segment| match segment.count > most.count {
+                true => segment,
+                false => most,
+            })
+    }
+
+    /// Returns the number of assignments covering the section.
+    pub fn count(&self, section: i64) -> usize {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.interval.end() < section);
+        self.segments
+            .get(index)
+            .filter(|segment| segment.interval.contains(section))
+            .map_or(0, |segment| segment.count)
+    }
+
+    /// Returns the coverage as a JSON object.
+    pub fn to_json(&self) -> String {
+        let intervals = |intervals: &[Interval]| -> String {
+            intervals
+                .iter()
+                .map(|interval| {
+                    format!(
+                        "{{\"start\":{},\"end\":{}}}",
+                        interval.start(),
+                        interval.end()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let segments: Vec<String> = self
+            .segments
+            .iter()
+            .map(|segment| {
+                format!(
+                    "{{\"start\":{},\"end\":{},\"count\":{}}}",
+                    segment.interval.start(),
+                    segment.interval.end(),
+                    segment.count
+                )
+            })
+            .collect();
+        let most_covered = match self.most_covered() {
+            Some(segment) => format!(
+                "{{\"section\":{},\"count\":{}}}",
+                segment.interval.start(),
+                segment.count
+            ),
+            None => "null".to_string(),
+        };
+
+        format!(
+            "{{\"assignments\":{},\"covered\":{},\"intervals\":[{}],\"gaps\":[{}],\"most_covered\":{},\"segments\":[{}]}}",
+            self.assignments,
+            self.covered.sections(),
+            intervals(self.covered.intervals()),
+            intervals(&self.covered.gaps()),
+            most_covered,
+            segments.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day4::Day4;
+    use crate::Solution;
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let set: IntervalSet = [
+            interval(8, 9),
+            interval(1, 3),
+            interval(4, 5),
+            interval(2, 2),
+            interval(i64::MAX, i64::MAX),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            set.intervals(),
+            [interval(1, 5), interval(8, 9), interval(i64::MAX, i64::MAX)]
+        );
+        assert_eq!(set.sections(), 8);
+        assert!(set.contains(5));
+        assert!(!set.contains(6));
+        assert_eq!(set.gaps()[0], interval(6, 7));
+    }
+
+    #[test]
+    fn test_coverage_counts_every_section() {
+        let groups = Day4::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8").unwrap();
+        let assignments: Vec<Interval> = groups.into_iter().flatten().collect();
+        let coverage = Coverage::new(assignments.iter().copied());
+
+        assert_eq!(coverage.assignments, 12);
+        for section in 0..=10 {
+            let expected = assignments
+                .iter()
+                .filter(|interval| interval.contains(section))
+                .count();
+            assert_eq!(coverage.count(section), expected, "section {}", section);
+            assert_eq!(coverage.covered.contains(section), expected > 0);
+        }
+        let most = coverage.most_covered().unwrap();
+        assert_eq!((most.interval, most.count), (interval(6, 6), 8));
+    }
+
+    #[test]
+    fn test_empty_coverage() {
+        let coverage = Coverage::new([]);
+        assert_eq!(coverage.most_covered(), None);
+        assert_eq!(
+            coverage.to_json(),
+            "{\"assignments\":0,\"covered\":0,\"intervals\":[],\"gaps\":[],\"most_covered\":null,\"segments\":[]}"
+        );
+    }
+}
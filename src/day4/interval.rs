@@ -0,0 +1,267 @@
+//! Intervals of sections with the relations of Allen's interval algebra.
+//!
+//! Sections are whole numbers, so an interval includes both of its bounds, and two intervals meet
+//! when one ends right before the other starts, without sharing a section.
+
+use std::fmt::Display;
+
+/// Sections from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+/// How an interval lies relative to another one, as one of the thirteen relations of Allen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// Ends at least a section before the other starts.
+    Before,
+    /// Ends right before the other starts.
+    Meets,
+    /// Starts first and ends inside the other.
+    Overlaps,
+    /// Starts with the other and ends first.
+    Starts,
+    /// Starts after and ends before the other.
+    During,
+    /// Starts after the other and ends with it.
+    Finishes,
+    /// Starts and ends with the other.
+    Equals,
+    /// Ends with the other and starts first.
+    FinishedBy,
+    /// Starts first and ends after the other.
+    Contains,
+    /// Starts with the other and ends after it.
+    StartedBy,
+    /// Starts inside the other and ends after it.
+    OverlappedBy,
+    /// Starts right after the other ends.
+    MetBy,
+    /// Starts at least a section after the other ends.
+    After,
+}
+
+impl Relation {
+    /// Relations in which one interval contains the other.
+    pub const CONTAINMENT: [Relation; 7] = [
+        Relation::Starts,
+        Relation::During,
+        Relation::Finishes,
+        Relation::Equals,
+        Relation::FinishedBy,
+        Relation::Contains,
+        Relation::StartedBy,
+    ];
+
+    /// Relations in which the intervals share no section.
+    pub const DISJOINT: [Relation; 4] = [
+        Relation::Before,
+        Relation::Meets,
+        Relation::MetBy,
+        Relation::After,
+    ];
+
+    /// Returns the relation of the other interval to the first one.
+    pub fn inverse(self) -> Relation {
+        match self {
+            Relation::Before => Relation::After,
+            Relation::Meets => Relation::MetBy,
+            Relation::Overlaps => Relation::OverlappedBy,
+            Relation::Starts => Relation::StartedBy,
+            Relation::During => Relation::Contains,
+            Relation::Finishes => Relation::FinishedBy,
+            Relation::Equals => Relation::Equals,
+            Relation::FinishedBy => Relation::Finishes,
+            Relation::Contains => Relation::During,
+            Relation::StartedBy => Relation::Starts,
+            Relation::OverlappedBy => Relation::Overlaps,
+            Relation::MetBy => Relation::Meets,
+            Relation::After => Relation::Before,
+        }
+    }
+}
+
+impl Interval {
+    /// Creates the interval from `start` to `end`, or returns `None` if `start` exceeds `end`.
+    pub fn new(start: i64, end: i64) -> Option<Interval> {
+        match start <= end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    /// Returns the first section.
+    pub fn start(self) -> i64 {
+        self.start
+    }
+
+    /// Returns the last section.
+    pub fn end(self) -> i64 {
+        self.end
+    }
+
+    /// Returns the number of sections. Panics if the interval covers every `i64`.
+    pub fn sections(self) -> u64 {
+        self.end.abs_diff(self.start) + 1
+    }
+
+    /// Returns whether the section is in the interval.
+    pub fn contains(self, section: i64) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /// Returns how the interval lies relative to the other one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day4::interval::{Interval, Relation};
+    ///
+    /// let a = Interval::new(2, 4).unwrap();
+    /// let b = Interval::new(5, 7).unwrap();
+    /// let c = Interval::new(4, 8).unwrap();
+    /// assert_eq!(a.relation(b), Relation::Meets);
+    /// assert_eq!(a.relation(c), Relation::Overlaps);
+    /// assert_eq!(c.relation(b), Relation::Contains);
+    /// ```
+    pub fn relation(self, other: Interval) -> Relation {
+        use std::cmp::Ordering::*;
+
+        if self.end < other.start {
+            return match self.end + 1 == other.start {
+                true => Relation::Meets,
+                false => Relation::Before,
+            };
+        }
+        if other.end < self.start {
+            return match other.end + 1 == self.start {
+                true => Relation::MetBy,
+                false => Relation::After,
+            };
+        }
+        match (self.start.cmp(&other.start), self.end.cmp(&other.end)) {
+            (Less, Less) => Relation::Overlaps,
+            (Less, Equal) => Relation::FinishedBy,
+            (Less, Greater) => Relation::Contains,
+            (Equal, Less) => Relation::Starts,
+            (Equal, Equal) => Relation::Equals,
+            (Equal, Greater) => Relation::StartedBy,
+            (Greater, Less) => Relation::During,
+            (Greater, Equal) => Relation::Finishes,
+            (Greater, Greater) => Relation::OverlappedBy,
+        }
+    }
+
+    /// Returns whether one of the intervals contains the other.
+    pub fn nests(self, other: Interval) -> bool {
+        Relation::CONTAINMENT.contains(&self.relation(other))
+    }
+
+    /// Returns whether the intervals share any section.
+    pub fn overlaps(self, other: Interval) -> bool {
+        !Relation::DISJOINT.contains(&self.relation(other))
+    }
+
+    /// Returns the sections in both intervals, or `None` if they share none.
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Returns the sections in either interval, or `None` if they are not a single interval.
+    ///
+    /// Intervals that meet are joined, as no section lies between them.
+    pub fn union(self, other: Interval) -> Option<Interval> {
+        match self.relation(other) {
+            Relation::Before | Relation::After => None,
+            _ => Some(Interval {
+                start: self.start.min(other.start),
+                end: self.end.max(other.end),
+            }),
+        }
+    }
+
+    /// Returns the sections of the interval outside the other one, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code_2022::day4::interval::Interval;
+    ///
+    /// let a = Interval::new(1, 9).unwrap();
+    /// let b = Interval::new(3, 5).unwrap();
+    /// assert_eq!(
+    ///     a.difference(b),
+    ///     vec![Interval::new(1, 2).unwrap(), Interval::new(6, 9).unwrap()]
+    /// );
+    /// assert!(b.difference(a).is_empty());
+    /// ```
+    pub fn difference(self, other: Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![self];
+        }
+        let before = match other.start > self.start {
+            true => Interval::new(self.start, other.start - 1),
+            false => None,
+        };
+        let after = match other.end < self.end {
+            true => Interval::new(other.end + 1, self.end),
+            false => None,
+        };
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_every_relation() {
+        let other = interval(5, 8);
+        for (start, end, relation) in [
+            (1, 3, Relation::Before),
+            (1, 4, Relation::Meets),
+            (3, 6, Relation::Overlaps),
+            (5, 6, Relation::Starts),
+            (6, 7, Relation::During),
+            (6, 8, Relation::Finishes),
+            (5, 8, Relation::Equals),
+            (4, 8, Relation::FinishedBy),
+            (4, 9, Relation::Contains),
+            (5, 9, Relation::StartedBy),
+            (7, 9, Relation::OverlappedBy),
+            (9, 10, Relation::MetBy),
+            (10, 12, Relation::After),
+        ] {
+            let a = interval(start, end);
+            assert_eq!(a.relation(other), relation, "{}", a);
+            assert_eq!(other.relation(a), relation.inverse(), "{}", a);
+            assert_eq!(a.intersection(other).is_some(), a.overlaps(other), "{}", a);
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = interval(-3, 2);
+        let b = interval(3, 6);
+        assert_eq!(a.sections(), 6);
+        assert_eq!(a.intersection(b), None);
+        assert_eq!(a.union(b), Some(interval(-3, 6)));
+        assert_eq!(a.union(interval(4, 6)), None);
+        assert_eq!(a.difference(b), vec![a]);
+        assert_eq!(a.difference(interval(0, 5)), vec![interval(-3, -1)]);
+        assert_eq!(interval(1, 1).to_string(), "1-1");
+    }
+}
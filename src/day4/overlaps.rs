@@ -0,0 +1,134 @@
+//! Every pair of overlapping assignments in the whole input, found with a sweep line.
+
+use super::interval::Interval;
+use super::Group;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Sections assigned to an elf at a line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Assignment {
+    /// Line number, starting from 1.
+    pub line: usize,
+    /// Position of the elf in the line, starting from 1.
+    pub elf: usize,
+    pub interval: Interval,
+}
+
+/// Two assignments sharing at least one section, the first one coming first in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Overlap {
+    pub first: Assignment,
+    pub second: Assignment,
+}
+
+/// Returns the assignments of every group of elves, with their lines and positions.
+pub fn assignments(groups: &[Group]) -> Vec<Assignment> {
+    groups
+        .iter()
+        .enumerate()
+        .flat_map(|(i, group)| {
+            group
+                .iter()
+                .copied()
+                .enumerate()
+                .map(move |(j, interval)| Assignment {
+                    line: i + 1,
+                    elf: j + 1,
+                    interval,
+                })
+        })
+        .collect()
+}
+
+/// Returns every pair of assignments sharing a section, in O(n log n + k) for n assignments and
+/// k pairs.
+///
+/// Assignments are swept in the order of their starts, keeping those still open in a heap ordered
+/// by their ends. When an assignment starts, the ones ending before it are closed, and it overlaps
+/// every one left open. Pairs are returned in the order of the start of their later assignment.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day4::interval::Interval;
+/// use advent_of_code_2022::day4::overlaps;
+///
+/// let groups = [vec![(2, 4), (6, 8)], vec![(4, 6), (9, 9)]]
+///     .map(|group| group.into_iter().map(|(a, b)| Interval::new(a, b).unwrap()).collect());
+/// let overlaps = overlaps::find(&overlaps::assignments(&groups));
+/// assert_eq!(overlaps.len(), 2);
+/// assert_eq!((overlaps[0].first.line, overlaps[0].second.line), (1, 2));
+/// ```
+pub fn find(assignments: &[Assignment]) -> Vec<Overlap> {
+    let mut sorted: Vec<&Assignment> = assignments.iter().collect();
+    sorted.sort_by_key(|assignment| (assignment.interval.start(), assignment.line, assignment.elf));
+
+    let mut open: BinaryHeap<Reverse<(i64, usize)>> = BinaryHeap::new();
+    let mut overlaps = Vec::new();
+    for (i, &assignment) in sorted.iter().enumerate() {
+        let start = assignment.interval.start();
+        while open.peek().is_some_and(|Reverse((end, _))| *end < start) {
+            open.pop();
+        }
+        for Reverse((_, j)) in &open {
+            let other = sorted[*j];
+            let (first, second) = match other < assignment {
+                true => (*other, *assignment),
+                false => (*assignment, *other),
+            };
+            overlaps.push(Overlap { first, second });
+        }
+        open.push(Reverse((assignment.interval.end(), i)));
+    }
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day4::Day4;
+    use crate::{input, Solution};
+    use std::collections::HashSet;
+
+    /// Compares every pair of assignments with each other.
+    fn find_naive(assignments: &[Assignment]) -> HashSet<Overlap> {
+        let mut overlaps = HashSet::new();
+        for (i, first) in assignments.iter().enumerate() {
+            for second in &assignments[i + 1..] {
+                if first.interval.overlaps(second.interval) {
+                    overlaps.insert(Overlap {
+                        first: *first,
+                        second: *second,
+                    });
+                }
+            }
+        }
+        overlaps
+    }
+
+    #[test]
+    fn test_find_matches_naive() {
+        let example = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+        let puzzle = input::read(4).unwrap();
+        let puzzle: Vec<&str> = puzzle.lines().take(200).collect();
+        for input in [example, &puzzle.join("\n")] {
+            let assignments = assignments(&Day4::parse(input).unwrap());
+            let overlaps = find(&assignments);
+            let unique: HashSet<Overlap> = overlaps.iter().copied().collect();
+            assert_eq!(unique.len(), overlaps.len());
+            assert_eq!(unique, find_naive(&assignments));
+        }
+    }
+
+    #[test]
+    fn test_same_line_overlaps_count_as_part_2() {
+        let input = input::read(4).unwrap();
+        let groups = Day4::parse(&input).unwrap();
+        let same_line = find(&assignments(&groups))
+            .iter()
+            .filter(|overlap| overlap.first.line == overlap.second.line)
+            .count();
+        assert_eq!(same_line as u32, Day4::part2(&groups));
+    }
+}
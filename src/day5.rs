@@ -1,84 +1,171 @@
 //! Day 5 of Advent of Code 2022
 
+use crate::{Error, Result, Solution};
 use std::collections::VecDeque;
-use std::fs;
 
-/// Prints the result of part 1.
+pub struct Day5;
+
+/// Starting stacks layout and the rearrangement procedure.
+#[derive(Debug)]
+pub struct Procedure {
+    layout: Vec<VecDeque<char>>,
+    instructions: Vec<Vec<usize>>,
+}
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Procedure> {
+        let layout = parse_layout(input)?;
+        let instructions = parse_instructions(input);
+        let converted = convert_instructions(&instructions, layout.len())?;
+        check_quantities(&layout, &instructions, &converted)?;
+        Ok(Procedure {
+            layout,
+            instructions: converted,
+        })
+    }
+
+    fn part1(procedure: &Procedure) -> String {
+        process_instructions(
+            &procedure.layout,
+            &procedure.instructions,
+            &execute_instruction_part_1,
+        )
+    }
+
+    fn part2(procedure: &Procedure) -> String {
+        process_instructions(
+            &procedure.layout,
+            &procedure.instructions,
+            &execute_instruction_part_2,
+        )
+    }
+}
+
+/// Returns the answer to part 1.
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the input file.
+/// * `input` - Puzzle input.
 ///
 /// # Examples
 ///
 /// ```
 /// use advent_of_code_2022::day5;
 ///
-/// day5::run1("/Users/ihar/Projects/advent-of-code-2022/input/day5.txt");
+/// let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+/// assert_eq!(day5::part_1(input).unwrap(), "CMZ");
 /// ```
-pub fn run1(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let result = part_1(&input);
-    println!("result: {}", result);
+pub fn part_1(input: &str) -> Result<String> {
+    Ok(Day5::part1(&Day5::parse(input)?))
 }
 
-/// Prints the result of part 2.
+/// Returns the answer to part 2.
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the input file.
+/// * `input` - Puzzle input.
 ///
 /// # Examples
 ///
 /// ```
 /// use advent_of_code_2022::day5;
 ///
-/// day5::run2("/Users/ihar/Projects/advent-of-code-2022/input/day5.txt");
+/// let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+/// assert_eq!(day5::part_2(input).unwrap(), "MCD");
 /// ```
-pub fn run2(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let result = part_2(&input);
-    println!("result: {}", result);
+pub fn part_2(input: &str) -> Result<String> {
+    Ok(Day5::part2(&Day5::parse(input)?))
 }
 
-fn part_1(input: &str) -> String {
-    let layout = parse_layout(input);
-    let instructions = parse_instructions(input);
-    let instructions = convert_instructions(&instructions);
-    process_instructions(&layout, &instructions, &execute_instruction_part_1)
-}
+/// Converts instructions like `move 1 from 2 to 1` to machine format.
+///
+/// # Arguments
+///
+/// * `instructions` - Instructions with their line numbers.
+/// * `num_stacks` - Amount of stacks the instructions may refer to.
+fn convert_instructions(
+    instructions: &[(usize, String)],
+    num_stacks: usize,
+) -> Result<Vec<Vec<usize>>> {
+    instructions
+        .iter()
+        .map(|(number, line)| {
+            let words: Vec<&str> = line.split(' ').collect();
+            let end = &line[line.len()..];
 
-fn part_2(input: &str) -> String {
-    let layout = parse_layout(input);
-    let instructions = parse_instructions(input);
-    let instructions = convert_instructions(&instructions);
-    process_instructions(&layout, &instructions, &execute_instruction_part_2)
+            let mut n = Vec::new();
+            for (position, keyword) in ["move", "from", "to"].iter().enumerate() {
+                let word = words.get(position * 2).copied().unwrap_or(end);
+                if word != *keyword {
+                    let reason = format!("expected '{}'", keyword);
+                    return Err(Error::parse(*number, line, word, &reason));
+                }
+                let value = words.get(position * 2 + 1).copied().unwrap_or(end);
+                let value = value
+                    .parse::<usize>()
+                    .map_err(|_| Error::parse(*number, line, value, "invalid number"))?;
+                n.push(value);
+            }
+            if let Some(word) = words.get(6) {
+                return Err(Error::parse(*number, line, word, "unexpected text"));
+            }
+
+            for (position, index) in n.iter_mut().enumerate().skip(1) {
+                if *index == 0 || *index > num_stacks {
+                    return Err(Error::parse(
+                        *number,
+                        line,
+                        words[position * 2 + 1],
+                        "unknown stack",
+                    ));
+                }
+                *index -= 1;
+            }
+            Ok(n)
+        })
+        .collect()
 }
 
-/// Converts instructions to machine format.
-fn convert_instructions(instructions: &Vec<String>) -> Vec<Vec<usize>> {
-    instructions.iter().fold(Vec::new(), |acc, instruction| {
-        let mut result: Vec<Vec<usize>> = acc.clone();
-        let mut n: Vec<usize> = instruction
-            .split(' ')
-            .skip(1)
-            .step_by(2)
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect();
-        n[1] = n[1] - 1;
-        n[2] = n[2] - 1;
-        result.push(n);
-        result
-    })
+/// Checks that no instruction moves more containers than its stack holds at that point.
+///
+/// # Arguments
+///
+/// * `layout` - Starting stacks layout.
+/// * `instructions` - Instructions with their line numbers, as in the input.
+/// * `converted` - The same instructions in machine format.
+fn check_quantities(
+    layout: &[VecDeque<char>],
+    instructions: &[(usize, String)],
+    converted: &[Vec<usize>],
+) -> Result<()> {
+    let mut heights: Vec<usize> = layout.iter().map(|stack| stack.len()).collect();
+    for ((number, line), instruction) in instructions.iter().zip(converted) {
+        let (qty, from, to) = (instruction[0], instruction[1], instruction[2]);
+        if qty > heights[from] {
+            let word = line.split(' ').nth(1).unwrap_or(line);
+            let reason = format!("not enough containers in stack {}", from + 1);
+            return Err(Error::parse(*number, line, word, &reason));
+        }
+        heights[from] -= qty;
+        heights[to] += qty;
+    }
+    Ok(())
 }
 
+/// Moves `qty` containers from one stack to another.
+type InstructionProcessor = dyn Fn(&mut [VecDeque<char>], usize, usize, usize);
+
 /// Executes instructions on the containers.
 fn process_instructions(
-    containers: &Vec<VecDeque<char>>,
-    instructions: &Vec<Vec<usize>>,
-    instruction_processor: &dyn Fn(&mut [VecDeque<char>], usize, usize, usize),
+    containers: &[VecDeque<char>],
+    instructions: &[Vec<usize>],
+    instruction_processor: &InstructionProcessor,
 ) -> String {
-    let mut result = containers.clone();
+    let mut result = containers.to_vec();
 
     instructions.iter().for_each(|instruction| {
         let qty = instruction[0];
@@ -88,9 +175,10 @@ fn process_instructions(
         instruction_processor(&mut result, qty, from, to);
     });
 
-    result.iter().fold(String::new(), |acc, container| {
-        acc + container[0].to_string().as_str()
-    })
+    result
+        .iter()
+        .filter_map(|container| container.front())
+        .collect()
 }
 
 /// Executes a single instruction while reversing the containers order.
@@ -102,73 +190,71 @@ fn execute_instruction_part_1(result: &mut [VecDeque<char>], qty: usize, from: u
 
 /// Executes a single instruction while preserving the containers order.
 fn execute_instruction_part_2(result: &mut [VecDeque<char>], qty: usize, from: usize, to: usize) {
-    let mut cargo: VecDeque<char> = VecDeque::new();
-    for _ in 0..qty {
-        let container = result[from].pop_front().unwrap();
-        cargo.push_front(container);
-    }
-    for container in cargo {
+    let cargo: Vec<char> = result[from].drain(..qty).collect();
+    for container in cargo.into_iter().rev() {
         result[to].push_front(container);
     }
 }
 
 /// Executes a single instruction.
 fn execute_instruction(result: &mut [VecDeque<char>], from: usize, to: usize) {
-    let cargo = result[from].pop_front().unwrap();
-    result[to].push_front(cargo);
+    if let Some(cargo) = result[from].pop_front() {
+        result[to].push_front(cargo);
+    }
 }
 
-/// Parses instructions from input.
-fn parse_instructions(input: &str) -> Vec<String> {
-    let instructions: String = input.split("\n\n").skip(1).take(1).collect();
-    let result: Vec<&str> = instructions.split('\n').collect();
-    result
-        .iter()
-        .map(|s| s.to_string())
-        .filter(|s| !s.is_empty())
+/// Parses instructions with their line numbers from input.
+fn parse_instructions(input: &str) -> Vec<(usize, String)> {
+    input
+        .lines()
+        .enumerate()
+        .skip_while(|(_, line)| !line.is_empty())
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| (i + 1, line.to_string()))
         .collect()
 }
 
 /// Returns an amount of stacks from the last line of the stacks layout.
-fn num_stacks(line: &str) -> usize {
+///
+/// # Arguments
+///
+/// * `number` - Line number of the line.
+/// * `line` - Last line of the stacks layout.
+fn num_stacks(number: usize, line: &str) -> Result<usize> {
     let mut result = 0;
     for s in line.split(' ') {
         if !s.is_empty() {
-            result = s.parse::<usize>().unwrap();
+            result = s
+                .parse::<usize>()
+                .map_err(|_| Error::parse(number, line, s, "invalid stack number"))?;
         }
     }
-    result
+    Ok(result)
 }
 
 /// Parses the stacks layout and containers' positions from input.
-fn parse_layout(input: &str) -> Vec<VecDeque<char>> {
-    let layout: String = input.split("\n\n").take(1).collect();
-    let rows: Vec<&str> = layout.split('\n').collect();
-    let num_stacks = num_stacks(rows[rows.len() - 1]);
-    let rows_without_last_line: Vec<&&str> = rows.iter().take(rows.len() - 1).collect();
+fn parse_layout(input: &str) -> Result<Vec<VecDeque<char>>> {
+    let rows: Vec<&str> = input.lines().take_while(|line| !line.is_empty()).collect();
+    let (last_row, rows_without_last_line) = rows.split_last().ok_or_else(|| {
+        let first = input.lines().next().unwrap_or(input);
+        Error::parse(1, first, &first[..0], "missing stacks layout")
+    })?;
+    let num_stacks = num_stacks(rows.len(), last_row)?;
 
-    let mut containers: Vec<VecDeque<char>> = Vec::new();
-    for _ in 0..num_stacks {
-        containers.push(VecDeque::new());
-    }
-
-    for stack in rows_without_last_line {
-        let mut container: Vec<char> = Vec::new();
+    let mut containers: Vec<VecDeque<char>> = vec![VecDeque::new(); num_stacks];
 
-        stack
-            .chars()
-            .skip(1)
-            .step_by(4)
-            .for_each(|c| container.push(c));
+    for row in rows_without_last_line {
+        let container: Vec<char> = row.chars().skip(1).step_by(4).collect();
 
-        for i in 0..num_stacks {
-            if container[i] != ' ' {
-                containers[i].push_back(container[i]);
+        for (i, stack) in containers.iter_mut().enumerate() {
+            match container.get(i) {
+                Some(' ') | None => {}
+                Some(c) => stack.push_back(*c),
             }
         }
     }
 
-    containers
+    Ok(containers)
 }
 
 #[cfg(test)]
@@ -192,13 +278,13 @@ move 1 from 1 to 2",
     #[test]
     fn test_part_1() {
         let input = input();
-        assert_eq!(part_1(&input), "CMZ");
+        assert_eq!(part_1(&input).unwrap(), "CMZ");
     }
 
     #[test]
     fn test_parse_layout() {
         let input = input();
-        let result = parse_layout(&input);
+        let result = parse_layout(&input).unwrap();
         dbg!(&result);
         assert_eq!(result, vec![vec!['N', 'Z'], vec!['D', 'C', 'M'], vec!['P']]);
     }
@@ -215,7 +301,7 @@ move 1 from 1 to 2",
     fn test_simplify_instructions() {
         let input = input();
         let instructions = parse_instructions(&input);
-        let result = convert_instructions(&instructions);
+        let result = convert_instructions(&instructions, 3).unwrap();
         dbg!(&result);
         assert_eq!(result.len(), 4);
     }
@@ -223,9 +309,9 @@ move 1 from 1 to 2",
     #[test]
     fn test_process_instructions_1() {
         let input = input();
-        let layout = parse_layout(&input);
+        let layout = parse_layout(&input).unwrap();
         let instructions = parse_instructions(&input);
-        let instructions = convert_instructions(&instructions);
+        let instructions = convert_instructions(&instructions, layout.len()).unwrap();
         let result = process_instructions(&layout, &instructions, &execute_instruction_part_1);
         assert_eq!(result, "CMZ");
     }
@@ -233,17 +319,64 @@ move 1 from 1 to 2",
     #[test]
     fn test_process_instructions_2() {
         let input = input();
-        let layout = parse_layout(&input);
+        let layout = parse_layout(&input).unwrap();
         let instructions = parse_instructions(&input);
-        let instructions = convert_instructions(&instructions);
+        let instructions = convert_instructions(&instructions, layout.len()).unwrap();
         let result = process_instructions(&layout, &instructions, &execute_instruction_part_2);
         assert_eq!(result, "MCD");
     }
 
+    #[test]
+    fn test_parse_too_many_containers() {
+        let error =
+            Day5::parse(&input().replace("move 1 from 1 to 2", "move 5 from 3 to 1")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 9, column 6: not enough containers in stack 3 '5'"
+        );
+
+        let input = input().replace("move 1 from 1 to 2", "move 4 from 3 to 1");
+        assert_eq!(part_1(&input).unwrap(), "P");
+        assert_eq!(part_2(&input).unwrap(), "D");
+    }
+
+    #[test]
+    fn test_parse_missing_layout() {
+        for input in ["\nmove 1 from 1 to 2", ""] {
+            let error = Day5::parse(input).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "line 1, column 1: missing stacks layout ''"
+            );
+        }
+    }
+
     #[test]
     fn test_num_stacks() {
         let input = " 1   2   3   4   5   6   7   8   9 ";
-        let result = num_stacks(&input);
+        let result = num_stacks(3, input).unwrap();
         assert_eq!(result, 9);
     }
+
+    #[test]
+    fn test_convert_instructions_invalid() {
+        let instructions = vec![(6, "move 1 from 2 to 1".to_string())];
+        assert!(convert_instructions(&instructions, 3).is_ok());
+
+        let instructions = vec![(7, "move x from 2 to 1".to_string())];
+        let error = convert_instructions(&instructions, 3).unwrap_err();
+        assert_eq!(error.to_string(), "line 7, column 6: invalid number 'x'");
+
+        let instructions = vec![(8, "move 1 from 2 into 1".to_string())];
+        let error = convert_instructions(&instructions, 3).unwrap_err();
+        assert_eq!(error.to_string(), "line 8, column 15: expected 'to' 'into'");
+
+        let instructions = vec![(9, "move 1 from 4 to 1".to_string())];
+        let error = convert_instructions(&instructions, 3).unwrap_err();
+        assert_eq!(error.to_string(), "line 9, column 13: unknown stack '4'");
+
+        let instructions = vec![(10, "move 1 from 2".to_string())];
+        let error = convert_instructions(&instructions, 3).unwrap_err();
+        assert_eq!(error.to_string(), "line 10, column 14: expected 'to' ''");
+    }
 }
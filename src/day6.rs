@@ -1,43 +1,69 @@
 //! Day 6 of Advent of Code 2022
 
-use std::fs;
+use crate::{Error, Result, Solution};
 
-/// Prints the result of part 1.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty());
+        let message = lines.next().map(|(_, line)| line).unwrap_or("");
+        match lines.next() {
+            Some((i, line)) => Err(Error::parse(i + 1, line, line, "unexpected line")),
+            None => Ok(message.to_string()),
+        }
+    }
+
+    fn part1(message: &String) -> usize {
+        process_message(message, 4)
+    }
+
+    fn part2(message: &String) -> usize {
+        process_message(message, 14)
+    }
+}
+
+/// Returns the answer to part 1.
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the input file.
+/// * `input` - Puzzle input.
 ///
 /// # Examples
 ///
 /// ```
 /// use advent_of_code_2022::day6;
 ///
-/// day6::run1("/Users/ihar/Projects/advent-of-code-2022/input/day6.txt");
+/// let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+/// assert_eq!(day6::part_1(input).unwrap(), 7);
 /// ```
-pub fn run1(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let result = process_message(&input, 4);
-    println!("result: {}", result);
+pub fn part_1(input: &str) -> Result<usize> {
+    Ok(Day6::part1(&Day6::parse(input)?))
 }
 
-/// Prints the result of part 2.
+/// Returns the answer to part 2.
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the input file.
+/// * `input` - Puzzle input.
 ///
 /// # Examples
 ///
 /// ```
 /// use advent_of_code_2022::day6;
 ///
-/// day6::run2("/Users/ihar/Projects/advent-of-code-2022/input/day6.txt");
+/// let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+/// assert_eq!(day6::part_2(input).unwrap(), 19);
 /// ```
-pub fn run2(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let result = process_message(&input, 14);
-    println!("result: {}", result);
+pub fn part_2(input: &str) -> Result<usize> {
+    Ok(Day6::part2(&Day6::parse(input)?))
 }
 
 /// Looks for n consecutive unique characters in the string.
@@ -96,4 +122,14 @@ mod tests {
             assert_eq!(process_message(&input, 14), expected);
         }
     }
+
+    #[test]
+    fn test_parse_multiple_lines() {
+        assert_eq!(Day6::parse("abcd\n\n").unwrap(), "abcd");
+        let error = Day6::parse("abcd\nefgh").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected line 'efgh'"
+        );
+    }
 }
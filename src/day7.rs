@@ -1,68 +1,105 @@
 //! Day 7 of Advent of Code 2022
 
 use crate::day7::Command::ChangeDir;
+use crate::{Error, Result, Solution};
 use std::fmt::Display;
-use std::fs;
 
-/// Prints the result of part 1.
+/// Total disk space available to the filesystem.
+const TOTAL_SIZE: usize = 70000000;
+/// Unused space needed to run the update.
+const LEAST_SIZE: usize = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = File;
+    type Answer1 = usize;
+    type Answer2 = Result<usize>;
+
+    fn parse(input: &str) -> Result<File> {
+        parse_input(input)
+    }
+
+    fn part1(root: &File) -> usize {
+        sum_of_small_dirs(root)
+    }
+
+    fn part2(root: &File) -> Result<usize> {
+        smallest_dir_to_delete(root)
+    }
+}
+
+/// Returns the answer to part 1.
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the input file.
+/// * `input` - Puzzle input.
 ///
 /// # Examples
 ///
 /// ```
 /// use advent_of_code_2022::day7;
 ///
-/// day7::run1("/Users/ihar/Projects/advent-of-code-2022/input/day7.txt");
+/// let input = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\n$ cd a\n$ ls\n29116 f";
+/// assert_eq!(day7::part_1(input).unwrap(), 29116);
 /// ```
-pub fn run1(path: &str) {
-    let input = fs::read_to_string(path).unwrap();
-    let root = parse_input(&input);
-    let mut dir_sizes = vec![];
-    root.get_dir_sizes(&mut dir_sizes);
-    let result = dir_sizes
-        .iter()
-        .filter(|size| **size < 100000)
-        .sum::<usize>();
-    println!("Day 7, part 1: {}", result);
+pub fn part_1(input: &str) -> Result<usize> {
+    Ok(Day7::part1(&parse_input(input)?))
 }
 
-/// Prints the result of part 2.
+/// Returns the answer to part 2.
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the input file.
+/// * `input` - Puzzle input.
 ///
 /// # Examples
 ///
 /// ```
 /// use advent_of_code_2022::day7;
 ///
-/// day7::run2("/Users/ihar/Projects/advent-of-code-2022/input/day7.txt");
+/// let input = "$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\n50000000 x\n$ cd ..\n$ cd b\n$ ls\n10000000 y";
+/// assert_eq!(day7::part_2(input).unwrap(), 50000000);
 /// ```
-pub fn run2(path: &str) {
-    let total_size = 70000000usize;
-    let least_size = 30000000usize;
+pub fn part_2(input: &str) -> Result<usize> {
+    Day7::part2(&parse_input(input)?)
+}
 
-    let input = fs::read_to_string(path).unwrap();
+/// Sums the sizes of the directories smaller than 100000.
+fn sum_of_small_dirs(root: &File) -> usize {
+    let mut dir_sizes = vec![];
+    root.get_dir_sizes(&mut dir_sizes);
+    dir_sizes
+        .iter()
+        .filter(|size| **size < 100000)
+        .sum::<usize>()
+}
 
-    let root = parse_input(&input);
+/// Returns how much space must be freed up to run the update, or an error if the files do not
+/// fit on the disk.
+fn target_size(root: &File) -> Result<usize> {
+    let size = root.size();
+    let unused = TOTAL_SIZE.checked_sub(size).ok_or_else(|| {
+        Error::Input(format!(
+            "files of {} exceed the disk of {}",
+            size, TOTAL_SIZE
+        ))
+    })?;
+    Ok(LEAST_SIZE.saturating_sub(unused))
+}
 
-    println!("Root size: {}", root.size());
-    println!("Free space: {}", total_size - root.size());
-    let target_size = least_size - (total_size - root.size());
-    println!("Need to free up: {}", target_size);
+/// Returns the size of the smallest directory which frees up enough space when deleted.
+fn smallest_dir_to_delete(root: &File) -> Result<usize> {
+    let target_size = target_size(root)?;
 
     let mut dir_sizes = vec![];
     root.get_dir_sizes(&mut dir_sizes);
 
-    dir_sizes.sort();
-
-    let proper_dir = dir_sizes.iter().filter(|size| **size >= target_size).min();
-
-    println!("Day 7, part 2: {}", proper_dir.unwrap());
+    Ok(dir_sizes
+        .into_iter()
+        .filter(|size| *size >= target_size)
+        .min()
+        .unwrap_or(0))
 }
 
 #[derive(Debug, PartialEq)]
@@ -80,8 +117,9 @@ impl Display for FileKind {
     }
 }
 
+/// A file or a directory with its children.
 #[derive(Debug)]
-struct File {
+pub struct File {
     kind: FileKind,
     name: String,
     size: usize,
@@ -105,6 +143,7 @@ impl File {
         self.children.iter().any(|child| child.name == name)
     }
 
+    #[cfg(test)]
     fn get_by_path(&self, path: Vec<String>) -> Option<&File> {
         if path.is_empty() {
             return None;
@@ -128,60 +167,49 @@ impl File {
         None
     }
 
-    fn add_file_by_path(&mut self, file: File, path: Vec<String>) {
-        // println!("Adding file {:?} to path {:?}", file, path);
+    /// Adds a file to the directory at the path, or returns an error for the line listing it if
+    /// the path does not lead to a directory of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File or directory to add.
+    /// * `path` - Names of the directories from this one down to the parent of the file.
+    /// * `number` - Line number of the line adding the file.
+    /// * `line` - Line adding the file.
+    fn add_file_by_path(
+        &mut self,
+        file: File,
+        path: &[String],
+        number: usize,
+        line: &str,
+    ) -> Result<()> {
+        let mismatch = || Error::parse(number, line, line, "path does not match the tree");
 
         if path.len() == 1 {
             if self.name == path[0] {
                 self.add_child(file);
-            } else if self.contains(&path[0]) {
-                let child = self
-                    .children
-                    .iter_mut()
-                    .find(|child| child.name == path[0])
-                    .unwrap();
-                child.add_child(file);
-            } else {
-                self.pretty_print(0);
-                panic!("Path does not match. Path: {:?}", path);
-            }
-        } else if path.len() == 2 {
-            if self.name == path[0] && self.contains(&path[1]) {
-                let child = self
-                    .children
-                    .iter_mut()
-                    .find(|child| child.name == path[1])
-                    .unwrap();
-                child.add_child(file);
-            } else if self.contains(&path[0]) {
-                let child = self
-                    .children
-                    .iter_mut()
-                    .find(|child| child.name == path[0])
-                    .unwrap();
-                child.add_file_by_path(file, path[1..].to_vec());
             } else {
-                self.pretty_print(0);
-                panic!("Path does not match. Path: {:?}", path);
+                self.child_mut(&path[0])
+                    .ok_or_else(mismatch)?
+                    .add_child(file);
             }
         } else if self.name == path[0] && self.contains(&path[1]) {
-            let child = self
-                .children
-                .iter_mut()
-                .find(|child| child.name == path[1])
-                .unwrap();
-            child.add_file_by_path(file, path[2..].to_vec());
-        } else if self.contains(&path[0]) {
-            let child = self
-                .children
-                .iter_mut()
-                .find(|child| child.name == path[0])
-                .unwrap();
-            child.add_file_by_path(file, path[1..].to_vec());
+            let child = self.child_mut(&path[1]).ok_or_else(mismatch)?;
+            match path.len() {
+                2 => child.add_child(file),
+                _ => child.add_file_by_path(file, &path[2..], number, line)?,
+            }
         } else {
-            self.pretty_print(0);
-            panic!("Path does not match. Path: {:?}", path);
+            self.child_mut(&path[0])
+                .ok_or_else(mismatch)?
+                .add_file_by_path(file, &path[1..], number, line)?;
         }
+        Ok(())
+    }
+
+    /// Returns the child with the given name.
+    fn child_mut(&mut self, name: &str) -> Option<&mut File> {
+        self.children.iter_mut().find(|child| child.name == name)
     }
 
     fn size(&self) -> usize {
@@ -201,11 +229,19 @@ impl File {
         }
     }
 
-    fn pretty_print(&self, indent: usize) {
-        println!("{}- {} ({})", " ".repeat(indent), self.name, self.kind);
+    /// Writes the file and its children as a tree, one per line, indented by their depth.
+    fn write_tree(&self, f: &mut std::fmt::Formatter<'_>, indent: usize) -> std::fmt::Result {
+        writeln!(f, "{}- {} ({})", " ".repeat(indent), self.name, self.kind)?;
         for child in &self.children {
-            child.pretty_print(indent + 2);
+            child.write_tree(f, indent + 2)?;
         }
+        Ok(())
+    }
+}
+
+impl Display for File {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_tree(f, 0)
     }
 }
 
@@ -215,45 +251,61 @@ enum Command {
     ChangeDir(String),
 }
 
-fn parse_line(line: &str) -> (Option<Command>, Option<File>) {
-    if line.starts_with('$') {
-        let command = line[1..].trim();
+/// Parses a line of the terminal output into a command or a listed file.
+///
+/// # Arguments
+///
+/// * `number` - Line number of the line.
+/// * `line` - Line of the terminal output.
+fn parse_line(number: usize, line: &str) -> Result<(Option<Command>, Option<File>)> {
+    if let Some(command) = line.strip_prefix('$') {
+        let command = command.trim();
         if command == "ls" {
-            (Some(Command::List), None)
-        } else if command.starts_with("cd") {
-            let dir = command[2..].trim();
-            (Some(ChangeDir(dir.to_string())), None)
+            Ok((Some(Command::List), None))
+        } else if let Some(dir) = command.strip_prefix("cd") {
+            let dir = dir.trim();
+            if dir.is_empty() {
+                return Err(Error::parse(number, line, dir, "missing directory"));
+            }
+            Ok((Some(ChangeDir(dir.to_string())), None))
         } else {
-            panic!("Unknown command: {}", command);
+            Err(Error::parse(number, line, command, "unknown command"))
         }
-    } else if line.starts_with("dir") {
-        let name = line.strip_prefix("dir").unwrap().trim().to_string();
-        (
+    } else if let Some(name) = line.strip_prefix("dir") {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(Error::parse(number, line, name, "missing directory name"));
+        }
+        Ok((
             None,
             Some(File {
                 kind: FileKind::Dir,
-                name,
+                name: name.to_string(),
                 size: 0,
                 children: Vec::new(),
             }),
-        )
+        ))
     } else {
-        let parts = line.split(' ').collect::<Vec<&str>>();
-        let size = parts[0].parse::<usize>().unwrap();
-        let name = parts[1].to_string();
-        (
+        let (size, name) = line.split_once(' ').unwrap_or((line, ""));
+        let size = size
+            .parse::<usize>()
+            .map_err(|_| Error::parse(number, line, size, "invalid file size"))?;
+        if name.is_empty() {
+            return Err(Error::parse(number, line, name, "missing file name"));
+        }
+        Ok((
             None,
             Some(File {
                 kind: FileKind::File,
-                name,
+                name: name.to_string(),
                 size,
                 children: Vec::new(),
             }),
-        )
+        ))
     }
 }
 
-fn parse_input(input: &str) -> File {
+fn parse_input(input: &str) -> Result<File> {
     let mut root = File {
         kind: FileKind::Dir,
         name: "/".to_string(),
@@ -264,18 +316,24 @@ fn parse_input(input: &str) -> File {
     let mut current_command = ChangeDir("/".to_string());
     let mut path = vec![root.name.clone()];
 
-    for line in input.lines() {
-        let (command, file) = parse_line(line);
+    for (i, line) in input.lines().enumerate() {
+        let (command, file) = parse_line(i + 1, line)?;
 
         if let Some(command) = command {
             current_command = command;
         }
 
-        // println!("Command: {:?}, file: {:?}", current_command, file);
-
         match &current_command {
             ChangeDir(dir) => {
                 if dir == ".." {
+                    if path.len() == 1 {
+                        return Err(Error::parse(
+                            i + 1,
+                            line,
+                            line,
+                            "cannot leave the root directory",
+                        ));
+                    }
                     path.pop();
                 } else if dir == "/" {
                     path = vec![dir.to_string()];
@@ -286,26 +344,25 @@ fn parse_input(input: &str) -> File {
                         size: 0,
                         children: Vec::new(),
                     };
-                    root.add_file_by_path(new_dir, path.clone());
+                    root.add_file_by_path(new_dir, &path, i + 1, line)?;
                     path.push(dir.to_string());
                 }
             }
             Command::List => {
                 if let Some(file) = file {
-                    root.add_file_by_path(file, path.clone());
+                    root.add_file_by_path(file, &path, i + 1, line)?;
                 }
             }
         }
-
-        // println!("Path: {:?}", path);
     }
 
-    root
+    Ok(root)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input;
 
     fn input() -> String {
         String::from(
@@ -337,13 +394,13 @@ $ ls
 
     #[test]
     fn test_parse_input() {
-        let root = parse_input(&input());
+        let root = parse_input(&input()).unwrap();
         dbg!("{:?}", root);
     }
 
     #[test]
     fn test_size() {
-        let root = parse_input(&input());
+        let root = parse_input(&input()).unwrap();
         let path = vec!["/".to_string(), "a".to_string(), "e".to_string()];
         let dir = root.get_by_path(path).unwrap();
         assert_eq!(dir.size(), 584);
@@ -363,7 +420,7 @@ $ ls
 
     #[test]
     fn test_dir_size() {
-        let root = parse_input(&input());
+        let root = parse_input(&input()).unwrap();
         let mut dir_sizes = vec![];
         root.get_dir_sizes(&mut dir_sizes);
         assert_eq!(
@@ -377,17 +434,68 @@ $ ls
 
     #[test]
     fn test_pretty_print() {
-        let root = parse_input(&input());
-        root.pretty_print(0);
+        let root = parse_input(&input()).unwrap();
+        let tree = root.to_string();
+        assert!(tree.starts_with("- / (dir)\n  - a (dir)\n    - e (dir)\n      - i (file)\n"));
+        assert_eq!(tree.lines().count(), 14);
     }
 
     #[test]
     fn test_run1() {
-        run1("/Users/ihar/Projects/advent-of-code-2022/input/day7.txt");
+        let input = input::read(7).unwrap();
+        assert_eq!(part_1(&input).unwrap(), 1118405);
     }
 
     #[test]
     fn test_run2() {
-        run2("/Users/ihar/Projects/advent-of-code-2022/input/day7.txt");
+        let input = input::read(7).unwrap();
+        assert_eq!(part_2(&input).unwrap(), 12545514);
+    }
+
+    #[test]
+    fn test_files_exceeding_the_disk() {
+        let input = "$ cd /\n$ ls\n80000000 big\n";
+        assert_eq!(part_1(input).unwrap(), 0);
+        let error = part_2(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "files of 80000000 exceed the disk of 70000000"
+        );
+    }
+
+    #[test]
+    fn test_parse_line_invalid() {
+        let error = parse_line(3, "$ rm -rf /").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: unknown command 'rm -rf /'"
+        );
+
+        let error = parse_line(4, "12k a.txt").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: invalid file size '12k'"
+        );
+    }
+
+    #[test]
+    fn test_add_file_outside_of_the_tree() {
+        let mut root = parse_input("$ cd /\n$ ls\ndir a").unwrap();
+        let line = "5 b.txt";
+        let (_, file) = parse_line(4, line).unwrap();
+        let path = ["/".to_string(), "x".to_string(), "y".to_string()];
+        let error = root
+            .add_file_by_path(file.unwrap(), &path, 4, line)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: path does not match the tree '5 b.txt'"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_leave_root() {
+        let error = parse_input("$ cd /\n$ cd ..").unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 2, .. }));
     }
 }
@@ -0,0 +1,145 @@
+//! Errors returned while reading and parsing puzzle inputs.
+
+use std::fmt::Display;
+use std::io;
+
+/// Error of reading or parsing a puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A line of the input could not be parsed.
+    Parse {
+        /// Line number, starting from 1.
+        line: usize,
+        /// Column of the offending text, starting from 1.
+        column: usize,
+        /// Offending text.
+        text: String,
+        /// What is wrong with the text.
+        reason: String,
+    },
+    /// A group of consecutive lines is invalid as a whole.
+    Group {
+        /// First line of the group, starting from 1.
+        first: usize,
+        /// Last line of the group.
+        last: usize,
+        /// What is wrong with the group.
+        reason: String,
+    },
+    /// The input is valid line by line, but has no answer as a whole.
+    Input(String),
+}
+
+/// Result with the crate's error.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Creates a parse error for the `text` found in the `line`.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - Line number, starting from 1.
+    /// * `line` - Whole line of the input.
+    /// * `text` - Offending part of the line. Must be a slice of `line`, otherwise the column points
+    ///   to the end of the line.
+    /// * `reason` - What is wrong with the text.
+    pub fn parse(number: usize, line: &str, text: &str, reason: &str) -> Error {
+        Error::Parse {
+            line: number,
+            column: column(line, text),
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Creates an error for the group of lines from `first` to `last`.
+    pub fn group(first: usize, last: usize, reason: &str) -> Error {
+        Error::Group {
+            first,
+            last,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Returns the column of `text` inside `line`, starting from 1.
+fn column(line: &str, text: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|offset| *offset <= line.len() && line.is_char_boundary(*offset))
+        .unwrap_or(line.len());
+    line[..offset].chars().count() + 1
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Input(reason) => write!(f, "{}", reason),
+            Error::Parse {
+                line,
+                column,
+                text,
+                reason,
+            } => write!(f, "line {}, column {}: {} '{}'", line, column, reason, text),
+            Error::Group {
+                first,
+                last,
+                reason,
+            } if first == last => write!(f, "line {}: {}", first, reason),
+            Error::Group {
+                first,
+                last,
+                reason,
+            } => write!(f, "lines {}-{}: {}", first, last, reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse { .. } | Error::Group { .. } | Error::Input(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let line = "A Q";
+        let error = Error::parse(3, line, &line[2..], "unknown shape");
+        assert_eq!(error.to_string(), "line 3, column 3: unknown shape 'Q'");
+    }
+
+    #[test]
+    fn test_group_error() {
+        let error = Error::group(4, 6, "no common item");
+        assert_eq!(error.to_string(), "lines 4-6: no common item");
+        let error = Error::group(7, 7, "incomplete group");
+        assert_eq!(error.to_string(), "line 7: incomplete group");
+    }
+
+    #[test]
+    fn test_column_outside_of_line() {
+        let line = "move 1 from 2";
+        let error = Error::parse(1, line, "", "missing destination");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 14: missing destination ''"
+        );
+    }
+}
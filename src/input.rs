@@ -0,0 +1,100 @@
+//! Locating puzzle input files.
+
+use crate::Result;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory with input files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Locates input files named like `day7.txt` and `day7_test.txt` in a directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locator {
+    dir: PathBuf,
+}
+
+impl Locator {
+    /// Creates a locator looking for input files in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Locator { dir: dir.into() }
+    }
+
+    /// Returns the directory with input files.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the path to the puzzle input of a day.
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    /// Returns the path to the example input of a day.
+    pub fn example_path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{}_test.txt", day))
+    }
+
+    /// Reads the puzzle input of a day.
+    pub fn read(&self, day: u32) -> Result<String> {
+        Ok(fs::read_to_string(self.path(day))?)
+    }
+
+    /// Reads the example input of a day.
+    pub fn read_example(&self, day: u32) -> Result<String> {
+        Ok(fs::read_to_string(self.example_path(day))?)
+    }
+}
+
+impl Default for Locator {
+    /// Looks in the directory set by [`INPUT_DIR_VAR`], falling back to `input/` of the project.
+    fn default() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => Locator::new(dir),
+            _ => Locator::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("input")),
+        }
+    }
+}
+
+/// Reads the puzzle input of a day with the default locator.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::{day6, input};
+///
+/// let message = input::read(6).unwrap();
+/// assert_eq!(day6::part_1(&message).unwrap(), 1300);
+/// ```
+pub fn read(day: u32) -> Result<String> {
+    Locator::default().read(day)
+}
+
+/// Reads the example input of a day with the default locator.
+pub fn read_example(day: u32) -> Result<String> {
+    Locator::default().read_example(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let locator = Locator::new("inputs");
+        assert_eq!(locator.path(7), Path::new("inputs/day7.txt"));
+        assert_eq!(locator.example_path(7), Path::new("inputs/day7_test.txt"));
+    }
+
+    #[test]
+    fn test_read() {
+        assert!(read(1).unwrap().starts_with("5686"));
+        assert!(read_example(1).unwrap().starts_with("1000"));
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let locator = Locator::new("no/such/directory");
+        assert!(locator.read(1).is_err());
+    }
+}
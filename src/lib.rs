@@ -1,3 +1,14 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod error;
+pub mod input;
+pub mod scaffold;
+
+pub use error::{Error, Result};
+
 pub mod day1;
 pub mod day2;
 pub mod day3;
@@ -5,3 +16,228 @@ pub mod day4;
 pub mod day5;
 pub mod day6;
 pub mod day7;
+
+/// A solution of a single day: parses the input once and solves both parts from it.
+pub trait Solution {
+    /// Input in the form both parts work with.
+    type Input;
+    /// Answer of part 1.
+    type Answer1: Output;
+    /// Answer of part 2.
+    type Answer2: Output;
+
+    /// Parses the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses the puzzle input read from a reader, e.g. a file or standard input.
+    fn parse_reader(mut reader: impl Read) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    /// Solves part 1.
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    /// Solves part 2.
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Answer a part solves to, or the error that keeps the input from having one.
+pub trait Output {
+    /// Returns the answer as it is printed.
+    fn into_answer(self) -> Result<String>;
+}
+
+impl Output for u32 {
+    fn into_answer(self) -> Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl Output for u64 {
+    fn into_answer(self) -> Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl Output for usize {
+    fn into_answer(self) -> Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl Output for String {
+    fn into_answer(self) -> Result<String> {
+        Ok(self)
+    }
+}
+
+impl<T: Output> Output for Result<T> {
+    fn into_answer(self) -> Result<String> {
+        self?.into_answer()
+    }
+}
+
+/// Part of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Returns the part with the given number.
+    pub fn from_number(number: u32) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Answer to a part with the time spent solving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub time: Duration,
+}
+
+/// Outcome of running a solution: the time spent parsing the input and the answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parses the input once and solves the requested parts, returning the answers in the same order.
+pub type Solver = fn(&str, &[Part]) -> Result<Run>;
+
+/// Parses the input with `S` once and returns the answers to the requested parts.
+///
+/// Returns an error if the input cannot be parsed or if a part has no answer for it.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Run> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&input).into_answer()?,
+                Part::Two => S::part2(&input).into_answer()?,
+            };
+            Ok(Answer {
+                part: *part,
+                value,
+                time: start.elapsed(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Run {
+        parse_time,
+        answers,
+    })
+}
+
+/// Every solution with the day it solves.
+pub const SOLUTIONS: [(u32, Solver); 7] = [
+    (1, solve::<day1::Day1>),
+    (2, solve::<day2::Day2>),
+    (3, solve::<day3::Day3>),
+    (4, solve::<day4::Day4>),
+    (5, solve::<day5::Day5>),
+    (6, solve::<day6::Day6>),
+    (7, solve::<day7::Day7>),
+];
+
+/// Returns the solver of the given day.
+pub fn solver(day: u32) -> Option<Solver> {
+    SOLUTIONS
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, solver)| *solver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver() {
+        let input = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+        let solver = solver(4).unwrap();
+        let values = |run: Run| {
+            run.answers
+                .into_iter()
+                .map(|answer| (answer.part, answer.value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            values(solver(input, &Part::ALL).unwrap()),
+            vec![(Part::One, "2".to_string()), (Part::Two, "4".to_string())]
+        );
+        assert_eq!(
+            values(solver(input, &[Part::Two]).unwrap()),
+            vec![(Part::Two, "4".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_solver_invalid_input() {
+        let error = solver(4).unwrap()("2-4,6-8\n2-3;4-5", &Part::ALL).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_solver_part_without_answer() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL";
+        let run = solver(3).unwrap()(input, &[Part::One]).unwrap();
+        assert_eq!(run.answers[0].value, "54");
+
+        let error = solver(3).unwrap()(input, &Part::ALL).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Group {
+                first: 1,
+                last: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let input = "A Y\nB X\nC Z\n";
+        let guide = day2::Day2::parse_reader(input.as_bytes()).unwrap();
+        assert_eq!(day2::Day2::part1(&guide), 15);
+    }
+
+    #[test]
+    fn test_solver_unknown_day() {
+        assert!(solver(0).is_none());
+        assert!(solver(26).is_none());
+    }
+}
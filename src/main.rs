@@ -1,5 +1,1484 @@
-use advent_of_code_2022::*;
+use advent_of_code_2022::answers::{Answers, Verdict};
+use advent_of_code_2022::day1::statistics::Statistics;
+use advent_of_code_2022::day1::stream;
+use advent_of_code_2022::day2::encoding::{self, Encoding};
+use advent_of_code_2022::day2::rules::Rules;
+use advent_of_code_2022::day2::simulation::{self, Fixed, Frequency, Mirror, Random, Strategy};
+use advent_of_code_2022::day3::priority::{self, Priorities};
+use advent_of_code_2022::day3::{validation, Grouping, Rucksack};
+use advent_of_code_2022::day4::coverage::Coverage;
+use advent_of_code_2022::day4::overlaps;
+use advent_of_code_2022::day4::Group;
+use advent_of_code_2022::input::{Locator, INPUT_DIR_VAR};
+use advent_of_code_2022::{day1, day2, day3, day4, scaffold, solver, Part, Solution, SOLUTIONS};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+const USAGE: &str = "Usage:
+    aoc --day N [--part P] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 1 --stats [--json] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 1 --stream [--top K] [--json] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 2 --encodings [--input PATH | --example] [--input-dir DIR]
+    aoc --day 2 --explain [--part P] [--csv] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 2 --simulate [--rounds N] [--seed S] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 3 --check [--csv] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 3 --priorities [--part P] [--alphabet ITEMS | --weights PATH] [--csv]
+                [--input PATH | --example] [--input-dir DIR]
+    aoc --day 4 --coverage [--json] [--input PATH | --example] [--input-dir DIR]
+    aoc --day 4 --overlaps [--csv] [--input PATH | --example] [--input-dir DIR]
+    aoc --all [--input-dir DIR]
+    aoc verify [--answers PATH] [--input-dir DIR]
+    aoc new-day N [--input-dir DIR]
+
+Commands:
+    verify               Runs every part of every day and compares the answers with the
+                         recorded ones.
+    new-day N            Creates src/dayN.rs from a template, registers it in src/lib.rs and
+                         the benchmarks, and creates empty dayN.txt and dayN_test.txt in the
+                         input directory.
+
+Options:
+    -d, --day N          Day of the puzzle to run.
+    -p, --part P         Part of the puzzle to run (1 or 2). Runs both parts if omitted.
+    -i, --input PATH     Path to the input file, or - to read standard input. Defaults to
+                         standard input when it is piped, and to dayN.txt in the input
+                         directory otherwise.
+    -e, --example        Uses dayN_test.txt from the input directory as the input.
+        --input-dir DIR  Directory with input files. Defaults to $AOC_INPUT_DIR or input/ of the
+                         project.
+    -a, --all            Runs every part of every day with the default inputs and prints a
+                         summary table with the time spent parsing and solving.
+        --answers PATH   Path to the recorded answers. Defaults to answers.toml of the project.
+        --stats          Prints statistics of the calories carried by the elves instead of the
+                         answers (day 1).
+        --stream         Reads the input line by line in constant memory and prints the elves
+                         carrying the most calories instead of the answers (day 1).
+        --top K          Number of elves printed by --stream. Defaults to 3.
+        --encodings      Prints the total score of every mapping of X, Y and Z to distinct
+                         shapes instead of the answers (day 2).
+        --simulate       Plays every pair of strategies against each other and prints the wins,
+                         draws, losses and average scores instead of the answers (day 2). The
+                         strategies are the guide, the elves' moves in it, the counter of the
+                         opponent's most frequent shape, the mirror of the opponent's last
+                         shape and a random one.
+        --explain        Prints the shapes, outcome and points of every round of the strategy
+                         guide read as in the part given with --part, or part 1, with a
+                         running total instead of the answers (day 2).
+        --check          Reports every rucksack with an odd number of items, invalid items, or no
+                         or several item types in both compartments instead of the answers, and
+                         fails if there are any (day 3).
+        --priorities     Prints how much every item type contributes to the sum of priorities
+                         of the part given with --part, or part 1, instead of the answers
+                         (day 3).
+        --alphabet ITEMS Item types of --priorities from the lowest priority, starting from 1.
+                         Defaults to a to z, then A to Z.
+        --weights PATH   File with the priorities of --priorities, an item type and its
+                         priority on every line.
+        --coverage       Prints the sections covered by any elf, the gaps between them, the
+                         section covered by most elves and the number of elves covering every
+                         section instead of the answers (day 4).
+        --overlaps       Prints every pair of assignments sharing a section, on any lines,
+                         instead of the answers (day 4).
+        --rounds N       Number of rounds of every match of --simulate. Defaults to 1000.
+        --seed S         Seed of the random strategy of --simulate. Defaults to 0.
+        --json           Prints a report as JSON instead of a table.
+        --csv            Prints a report as CSV instead of a table.
+    -h, --help           Prints this message.";
+
+/// Number of buckets in the histogram of the statistics of day 1.
+const HISTOGRAM_BUCKETS: usize = 10;
+/// Length of the longest bar in the histogram of the statistics of day 1.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// What the binary has been asked to do.
+#[derive(Debug, PartialEq)]
+enum Command {
+    Help,
+    All {
+        locator: Locator,
+    },
+    Verify {
+        answers: PathBuf,
+        locator: Locator,
+    },
+    NewDay {
+        day: u32,
+        locator: Locator,
+    },
+    Run {
+        day: u32,
+        part: Option<u32>,
+        input: Input,
+        locator: Locator,
+    },
+    /// Elves carrying the most calories, found while streaming the input of day 1.
+    Stream {
+        top: usize,
+        format: Format,
+        input: Input,
+        locator: Locator,
+    },
+    Report {
+        day: u32,
+        report: Report,
+        format: Format,
+        input: Input,
+        locator: Locator,
+    },
+}
+
+/// Report about the input of a day printed instead of the answers.
+#[derive(Debug, Clone, PartialEq)]
+enum Report {
+    /// Statistics of the calories carried by the elves.
+    Stats,
+    /// Scores of every mapping of the second column of the strategy guide to shapes.
+    Encodings,
+    /// Tournament between strategies including the strategy guide.
+    Simulate { rounds: usize, seed: u64 },
+    /// Score of every round of the strategy guide read as in one of the parts.
+    Explain { part: u32 },
+    /// Problems of every invalid rucksack.
+    Check,
+    /// Contribution of every item type to the sum of priorities of one of the parts.
+    Priorities { part: u32, scheme: Scheme },
+    /// Sections covered by the assignments of every elf.
+    Coverage,
+    /// Every pair of assignments sharing a section.
+    Overlaps,
+}
+
+/// Where the priorities of the item types of day 3 come from.
+#[derive(Debug, Clone, PartialEq)]
+enum Scheme {
+    /// Letters from `a` to `z`, then from `A` to `Z`.
+    Letters,
+    /// Alphabet given on the command line.
+    Alphabet(String),
+    /// Weight file.
+    Weights(PathBuf),
+}
+
+impl Report {
+    /// Returns the day the report is about.
+    fn day(&self) -> u32 {
+        match self {
+            Report::Stats => 1,
+            Report::Encodings | Report::Simulate { .. } | Report::Explain { .. } => 2,
+            Report::Check | Report::Priorities { .. } => 3,
+            Report::Coverage | Report::Overlaps => 4,
+        }
+    }
+
+    /// Returns the option requesting the report.
+    fn option(&self) -> &'static str {
+        match self {
+            Report::Stats => "--stats",
+            Report::Encodings => "--encodings",
+            Report::Simulate { .. } => "--simulate",
+            Report::Explain { .. } => "--explain",
+            Report::Check => "--check",
+            Report::Priorities { .. } => "--priorities",
+            Report::Coverage => "--coverage",
+            Report::Overlaps => "--overlaps",
+        }
+    }
+
+    /// Returns whether the report can be printed in the format.
+    fn supports(&self, format: Format) -> bool {
+        match self {
+            Report::Stats | Report::Coverage => format != Format::Csv,
+            Report::Encodings | Report::Simulate { .. } => format == Format::Table,
+            Report::Explain { .. }
+            | Report::Check
+            | Report::Priorities { .. }
+            | Report::Overlaps => format != Format::Json,
+        }
+    }
+}
+
+/// How a report is printed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Returns the option requesting the format.
+    fn option(self) -> &'static str {
+        match self {
+            Format::Table => "the default table format",
+            Format::Json => "--json",
+            Format::Csv => "--csv",
+        }
+    }
+}
+
+/// Where the input of a day comes from.
+#[derive(Debug, PartialEq)]
+enum Input {
+    /// The puzzle input from the input directory.
+    Puzzle,
+    /// The example input from the input directory.
+    Example,
+    /// A file given on the command line.
+    File(PathBuf),
+    /// Standard input, requested with `--input -`.
+    Stdin,
+    /// Text piped into standard input.
+    Piped(String),
+}
 
 fn main() {
-    day7::run2("/Users/ihar/Projects/advent-of-code-2022/input/day7.txt");
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Command::All { locator } => run_all(&locator),
+        Command::Verify { answers, locator } => verify(&answers, &locator),
+        Command::NewDay { day, locator } => new_day(day, &locator),
+        Command::Run {
+            day,
+            part,
+            input,
+            locator,
+        } => read_stdin(input).and_then(|input| run(day, part, &input, &locator)),
+        Command::Stream {
+            top,
+            format,
+            input,
+            locator,
+        } => print_stream(top, format, &input, &locator),
+        Command::Report {
+            day,
+            report,
+            format,
+            input,
+            locator,
+        } => {
+            read_stdin(input).and_then(|input| print_report(day, report, format, &input, &locator))
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+/// Parses command line arguments into a command.
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut all = false;
+    let mut day = None;
+    let mut part = None;
+    let mut input = Input::Puzzle;
+    let mut input_dir = None;
+    let mut answers = None;
+    let mut report = None;
+    let mut stream = None;
+    let mut format = Format::Table;
+    let mut top = None;
+    let mut rounds = None;
+    let mut seed = None;
+    let mut scheme = None;
+
+    let (verify, args) = match args.split_first() {
+        Some((command, rest)) if command == "verify" => (true, rest),
+        _ => (false, args),
+    };
+    let (new_day, args) = match args.split_first() {
+        Some((command, rest)) if command == "new-day" => {
+            let (day, rest) = rest
+                .split_first()
+                .ok_or_else(|| "missing day for new-day".to_string())?;
+            (Some(parse_number(command, Some(day))?), rest)
+        }
+        _ => (None, args),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-a" | "--all" => all = true,
+            "-d" | "--day" => day = Some(parse_number(arg, args.next())?),
+            "-p" | "--part" => part = Some(parse_number(arg, args.next())?),
+            "-i" | "--input" => {
+                input = match parse_path(arg, args.next())? {
+                    path if path.as_os_str() == "-" => Input::Stdin,
+                    path => Input::File(path),
+                }
+            }
+            "-e" | "--example" => input = Input::Example,
+            "--input-dir" => input_dir = Some(parse_path(arg, args.next())?),
+            "--answers" => answers = Some(parse_path(arg, args.next())?),
+            "--stats" => report = Some(Report::Stats),
+            "--stream" => stream = Some(3),
+            "--encodings" => report = Some(Report::Encodings),
+            "--explain" => report = Some(Report::Explain { part: 1 }),
+            "--check" => report = Some(Report::Check),
+            "--coverage" => report = Some(Report::Coverage),
+            "--overlaps" => report = Some(Report::Overlaps),
+            "--priorities" => {
+                report = Some(Report::Priorities {
+                    part: 1,
+                    scheme: Scheme::Letters,
+                })
+            }
+            "--alphabet" => {
+                let alphabet = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", arg))?;
+                scheme = Some(Scheme::Alphabet(alphabet.clone()))
+            }
+            "--weights" => scheme = Some(Scheme::Weights(parse_path(arg, args.next())?)),
+            "--simulate" => {
+                report = Some(Report::Simulate {
+                    rounds: 1000,
+                    seed: 0,
+                })
+            }
+            "--top" => top = Some(parse_number(arg, args.next())?),
+            "--rounds" => rounds = Some(parse_number(arg, args.next())?),
+            "--seed" => seed = Some(parse_number(arg, args.next())?),
+            "--json" => format = Format::Json,
+            "--csv" => format = Format::Csv,
+            _ => return Err(format!("unknown argument: {}", arg)),
+        }
+    }
+
+    if let (Some(_), Some(report)) = (stream, &report) {
+        return Err(format!(
+            "--stream cannot be combined with {}",
+            report.option()
+        ));
+    }
+    let stream = stream.map(|default| top.take().unwrap_or(default));
+    let report = match report {
+        Some(Report::Simulate {
+            rounds: default_rounds,
+            seed: default_seed,
+        }) => Some(Report::Simulate {
+            rounds: rounds.take().unwrap_or(default_rounds),
+            seed: seed.take().unwrap_or(default_seed),
+        }),
+        Some(Report::Priorities {
+            part,
+            scheme: default,
+        }) => Some(Report::Priorities {
+            part,
+            scheme: scheme.take().unwrap_or(default),
+        }),
+        report => report,
+    };
+    for (given, option, required) in [
+        (top.is_some(), "--top", "--stream"),
+        (rounds.is_some(), "--rounds", "--simulate"),
+        (seed.is_some(), "--seed", "--simulate"),
+        (
+            matches!(scheme, Some(Scheme::Alphabet(_))),
+            "--alphabet",
+            "--priorities",
+        ),
+        (
+            matches!(scheme, Some(Scheme::Weights(_))),
+            "--weights",
+            "--priorities",
+        ),
+    ] {
+        if given {
+            return Err(format!("{} requires {}", option, required));
+        }
+    }
+
+    let locator = input_dir.map(Locator::new).unwrap_or_default();
+    if let Some(new_day) = new_day {
+        if verify
+            || all
+            || day.is_some()
+            || part.is_some()
+            || input != Input::Puzzle
+            || answers.is_some()
+            || report.is_some()
+            || stream.is_some()
+            || format != Format::Table
+        {
+            return Err("new-day accepts only --input-dir".to_string());
+        }
+        if !(1..=25).contains(&new_day) {
+            return Err(format!("invalid day for new-day: {}", new_day));
+        }
+        return Ok(Command::NewDay {
+            day: new_day,
+            locator,
+        });
+    }
+    if verify {
+        if all
+            || day.is_some()
+            || part.is_some()
+            || input != Input::Puzzle
+            || report.is_some()
+            || stream.is_some()
+            || format != Format::Table
+        {
+            return Err("verify accepts only --answers and --input-dir".to_string());
+        }
+        let answers = answers.unwrap_or_else(Answers::default_path);
+        return Ok(Command::Verify { answers, locator });
+    }
+    if answers.is_some() {
+        return Err("--answers can only be used with verify".to_string());
+    }
+    if let Some(top) = stream {
+        if all || part.is_some() {
+            return Err("--stream cannot be combined with --all or --part".to_string());
+        }
+        if format == Format::Csv {
+            return Err("--stream cannot be printed with --csv".to_string());
+        }
+        return match day {
+            Some(1) => Ok(Command::Stream {
+                top,
+                format,
+                input,
+                locator,
+            }),
+            _ => Err("--stream requires --day 1".to_string()),
+        };
+    }
+    if let Some(report) = report {
+        let report = match (report, part) {
+            (Report::Explain { .. } | Report::Priorities { .. }, Some(part))
+                if Part::from_number(part).is_none() =>
+            {
+                return Err(format!("unknown part: {}", part))
+            }
+            (Report::Explain { .. }, Some(part)) => Report::Explain { part },
+            (Report::Priorities { scheme, .. }, Some(part)) => Report::Priorities { part, scheme },
+            (report, _) => report,
+        };
+        let parts = matches!(report, Report::Explain { .. } | Report::Priorities { .. });
+        if all || (part.is_some() && !parts) {
+            return Err(format!(
+                "{} cannot be combined with --all or --part",
+                report.option()
+            ));
+        }
+        if !report.supports(format) {
+            return Err(format!(
+                "{} cannot be printed with {}",
+                report.option(),
+                format.option()
+            ));
+        }
+        return match day {
+            Some(day) if day == report.day() => Ok(Command::Report {
+                day,
+                report,
+                format,
+                input,
+                locator,
+            }),
+            _ => Err(format!(
+                "{} requires --day {}",
+                report.option(),
+                report.day()
+            )),
+        };
+    }
+    if format != Format::Table {
+        return Err(format!(
+            "{} requires a report like --stats",
+            format.option()
+        ));
+    }
+
+    match (all, day) {
+        (true, None) if part.is_none() && input == Input::Puzzle => Ok(Command::All { locator }),
+        (true, _) => Err("--all cannot be combined with --day, --part or --input".to_string()),
+        (false, Some(day)) => Ok(Command::Run {
+            day,
+            part,
+            input,
+            locator,
+        }),
+        (false, None) => Err("missing --day".to_string()),
+    }
+}
+
+/// Parses the value of a numeric option.
+fn parse_number<T: FromStr>(option: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {}", option))?;
+    value
+        .parse::<T>()
+        .map_err(|_| format!("invalid value for {}: {}", option, value))
+}
+
+/// Parses the value of a path option.
+fn parse_path(option: &str, value: Option<&String>) -> Result<PathBuf, String> {
+    value
+        .map(PathBuf::from)
+        .ok_or_else(|| format!("missing value for {}", option))
+}
+
+/// Runs one or both parts of a day.
+fn run(day: u32, part: Option<u32>, input: &Input, locator: &Locator) -> Result<(), String> {
+    let solver = solver(day).ok_or_else(|| format!("unknown day: {}", day))?;
+    let parts = match part {
+        Some(number) => {
+            vec![Part::from_number(number).ok_or_else(|| format!("unknown part: {}", number))?]
+        }
+        None => Part::ALL.to_vec(),
+    };
+
+    let (input, path) = read_input(day, input, locator)?;
+    let run = solver(&input, &parts).map_err(|e| format!("{}: {}", path.display(), e))?;
+    for answer in run.answers {
+        println!("Day {}, part {}: {}", day, answer.part, answer.value);
+    }
+    Ok(())
+}
+
+/// Reads the input of a day, returning it with the path it was read from.
+fn read_input(day: u32, input: &Input, locator: &Locator) -> Result<(String, PathBuf), String> {
+    let path = match input {
+        Input::Puzzle => locator.path(day),
+        Input::Example => locator.example_path(day),
+        Input::File(path) => path.clone(),
+        Input::Stdin | Input::Piped(_) => PathBuf::from("standard input"),
+    };
+    let input = match input {
+        Input::Piped(text) => text.clone(),
+        _ => fs::read_to_string(&path).map_err(|e| input_error(&path, e))?,
+    };
+    Ok((input, path))
+}
+
+/// Opens the input of a day to be read line by line, returning it with the path it is read from.
+///
+/// Like [`read_stdin`], reads standard input if it was requested, or if it is piped and the input
+/// was not given, falling back to the puzzle input when nothing is piped.
+fn open_input(
+    day: u32,
+    input: &Input,
+    locator: &Locator,
+) -> Result<(Box<dyn BufRead>, PathBuf), String> {
+    let stdin = PathBuf::from("standard input");
+    let path = match input {
+        Input::Puzzle if !io::stdin().is_terminal() => {
+            let mut lock = io::stdin().lock();
+            let piped = !lock
+                .fill_buf()
+                .map_err(|e| format!("cannot read standard input: {}", e))?
+                .is_empty();
+            if piped {
+                return Ok((Box::new(lock), stdin));
+            }
+            locator.path(day)
+        }
+        Input::Puzzle => locator.path(day),
+        Input::Example => locator.example_path(day),
+        Input::File(path) => path.clone(),
+        Input::Stdin => return Ok((Box::new(io::stdin().lock()), stdin)),
+        Input::Piped(text) => return Ok((Box::new(Cursor::new(text.clone())), stdin)),
+    };
+    let file = File::open(&path).map_err(|e| input_error(&path, e))?;
+    Ok((Box::new(BufReader::new(file)), path))
+}
+
+/// Returns the message of an input file that cannot be read.
+fn input_error(path: &Path, e: io::Error) -> String {
+    format!(
+        "cannot read input file {}: {} (set --input-dir or ${} to change the input directory)",
+        path.display(),
+        e,
+        INPUT_DIR_VAR
+    )
+}
+
+/// Prints a report about the input of a day.
+fn print_report(
+    day: u32,
+    report: Report,
+    format: Format,
+    input: &Input,
+    locator: &Locator,
+) -> Result<(), String> {
+    let (input, path) = read_input(day, input, locator)?;
+    let output = match report {
+        Report::Stats => day1::Day1::parse(&input).map(|inventory| {
+            let statistics = Statistics::new(&inventory, HISTOGRAM_BUCKETS);
+            match format {
+                Format::Table | Format::Csv => format_statistics(&statistics),
+                Format::Json => statistics.to_json() + "\n",
+            }
+        }),
+        Report::Encodings => format_encodings(&input),
+        Report::Simulate { rounds, seed } => format_simulation(&input, rounds, seed),
+        Report::Explain { part } => format_explanation(&input, part, format),
+        Report::Check => return print_check(&input, &path, format),
+        Report::Coverage => day4::Day4::parse(&input).map(|groups| {
+            let coverage = Coverage::new(groups.into_iter().flatten());
+            match format {
+                Format::Table | Format::Csv => format_coverage(&coverage),
+                Format::Json => coverage.to_json() + "\n",
+            }
+        }),
+        Report::Overlaps => {
+            day4::Day4::parse(&input).map(|groups| format_overlaps(&groups, format))
+        }
+        Report::Priorities { part, scheme } => {
+            format_priorities(&input, part, &load_priorities(&scheme)?, format)
+        }
+    };
+    print!(
+        "{}",
+        output.map_err(|e| format!("{}: {}", path.display(), e))?
+    );
+    Ok(())
+}
+
+/// Streams the input of day 1 and prints the elves carrying the most calories.
+fn print_stream(
+    top: usize,
+    format: Format,
+    input: &Input,
+    locator: &Locator,
+) -> Result<(), String> {
+    let (reader, path) = open_input(1, input, locator)?;
+    let summary = stream::top_k(reader, top).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    if format == Format::Json {
+        println!("{}", summary.to_json());
+        return Ok(());
+    }
+
+    let total = match summary.top_total() {
+        Some(total) => total.to_string(),
+        None => "overflow".to_string(),
+    };
+    let overview = [
+        ["Elves".to_string(), summary.elves.to_string()],
+        ["Items".to_string(), summary.items.to_string()],
+        [format!("Top {}", summary.top.len()), total],
+    ];
+    let mut leaders = vec![["Rank", "Elf", "Calories"].map(String::from)];
+    for (i, leader) in summary.top.iter().enumerate() {
+        leaders.push([
+            (i + 1).to_string(),
+            leader.index.to_string(),
+            leader.calories.to_string(),
+        ]);
+    }
+    print!(
+        "{}\n{}",
+        format_table(&overview, &[0]),
+        format_table(&leaders, &[])
+    );
+    Ok(())
+}
+
+/// Prints the problems of every invalid rucksack of day 3, failing if there are any.
+fn print_check(input: &str, path: &Path, format: Format) -> Result<(), String> {
+    let issues = validation::check(input);
+    if issues.is_empty() && format == Format::Table {
+        println!("{}: every rucksack is valid", path.display());
+        return Ok(());
+    }
+
+    let mut rows = vec![["Line", "Problem"].map(String::from)];
+    for issue in &issues {
+        rows.push([issue.line.to_string(), issue.problem.to_string()]);
+    }
+    match format {
+        Format::Csv => {
+            rows[0] = ["line", "problem"].map(String::from);
+            print!("{}", format_csv(&rows));
+        }
+        _ => print!("{}", format_table(&rows, &[1])),
+    }
+    match issues.len() {
+        0 => Ok(()),
+        1 => Err(format!("{}: 1 problem found", path.display())),
+        n => Err(format!("{}: {} problems found", path.display(), n)),
+    }
+}
+
+/// Loads the priorities of the item types of day 3.
+fn load_priorities(scheme: &Scheme) -> Result<Priorities, String> {
+    match scheme {
+        Scheme::Letters => Ok(Priorities::default()),
+        Scheme::Alphabet(alphabet) => {
+            Priorities::from_alphabet(alphabet).map_err(|e| format!("--alphabet: {}", e))
+        }
+        Scheme::Weights(path) => {
+            let weights = fs::read_to_string(path)
+                .map_err(|e| format!("cannot read weight file {}: {}", path.display(), e))?;
+            Priorities::parse(&weights).map_err(|e| format!("{}: {}", path.display(), e))
+        }
+    }
+}
+
+/// Formats how much every item type contributes to the sum of priorities of day 3 in the given
+/// part: the items in both compartments of every rucksack, or the badges of every group.
+fn format_priorities(
+    input: &str,
+    part: u32,
+    priorities: &Priorities,
+    format: Format,
+) -> advent_of_code_2022::Result<String> {
+    let rucksacks = day3::parse_with(input.as_bytes(), priorities)?;
+    let sets = match part {
+        1 => rucksacks.iter().map(Rucksack::shared).collect(),
+        _ => {
+            let groups = day3::groups(&rucksacks, Grouping::Chunks(day3::GROUP_SIZE))?;
+            for group in &groups {
+                group.badge(priorities)?;
+            }
+            groups
+                .into_iter()
+                .map(|group| group.common)
+                .collect::<Vec<_>>()
+        }
+    };
+    let contributions = priority::contributions(priorities, sets);
+
+    let mut rows = vec![["Item", "Priority", "Count", "Total"].map(String::from)];
+    for contribution in &contributions {
+        rows.push([
+            contribution.item.to_string(),
+            contribution.priority.to_string(),
+            contribution.count.to_string(),
+            contribution.total.to_string(),
+        ]);
+    }
+
+    if format == Format::Csv {
+        rows[0] = ["item", "priority", "count", "total"].map(String::from);
+        return Ok(format_csv(&rows));
+    }
+    let total: u64 = contributions.iter().map(|c| c.total).sum();
+    rows.push([
+        "Sum".to_string(),
+        String::new(),
+        String::new(),
+        total.to_string(),
+    ]);
+    Ok(format_table(&rows, &[0]))
+}
+
+/// Formats the total score of the strategy guide of day 2 under every mapping of the second column
+/// to shapes, from the highest score.
+fn format_encodings(input: &str) -> advent_of_code_2022::Result<String> {
+    let rules = Rules::classic();
+    let mut scores = Encoding::parse(&rules, encoding::PART_1)?
+        .shape_permutations(&rules)
+        .into_iter()
+        .map(|encoding| {
+            let rounds = encoding.parse_guide(&rules, input)?;
+            Ok((encoding.score(&rules, &rounds), encoding))
+        })
+        .collect::<advent_of_code_2022::Result<Vec<_>>>()?;
+    scores.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    let mut rows = vec![["Encoding", "Score"].map(String::from)];
+    for (score, encoding) in scores {
+        rows.push([encoding.to_string(), score.to_string()]);
+    }
+    Ok(format_table(&rows, &[0]))
+}
+
+/// Formats how every round of the strategy guide of day 2 is scored, reading the guide as in the
+/// given part.
+fn format_explanation(
+    input: &str,
+    part: u32,
+    format: Format,
+) -> advent_of_code_2022::Result<String> {
+    let rules = Rules::classic();
+    let spec = match part {
+        1 => encoding::PART_1,
+        _ => encoding::PART_2,
+    };
+    let rounds = Encoding::parse(&rules, spec)?.parse_guide(&rules, input)?;
+
+    let mut rows = vec![[
+        "Line",
+        "Opponent",
+        "Ours",
+        "Outcome",
+        "Shape points",
+        "Outcome points",
+        "Total",
+    ]
+    .map(String::from)];
+    for step in day2::explain(&rules, &rounds) {
+        rows.push([
+            step.line.to_string(),
+            rules.name(step.opponent).to_string(),
+            rules.name(step.ours).to_string(),
+            step.outcome.to_string(),
+            step.shape_points.to_string(),
+            step.outcome_points.to_string(),
+            step.total.to_string(),
+        ]);
+    }
+
+    if format == Format::Csv {
+        rows[0] = [
+            "line",
+            "opponent",
+            "ours",
+            "outcome",
+            "shape_points",
+            "outcome_points",
+            "total",
+        ]
+        .map(String::from);
+        return Ok(format_csv(&rows));
+    }
+    Ok(format_table(&rows, &[1, 2, 3]))
+}
+
+/// Plays a tournament between the strategy guide of day 2, the elves' moves in it and adaptive and
+/// random strategies, and formats the results of every match.
+fn format_simulation(input: &str, rounds: usize, seed: u64) -> advent_of_code_2022::Result<String> {
+    let rules = Rules::classic();
+    let guide = Encoding::parse(&rules, encoding::PART_1)?.parse_guide(&rules, input)?;
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Frequency::default()),
+        Box::new(Mirror),
+        Box::new(Random::new(seed)),
+    ];
+    if !guide.is_empty() {
+        let ours = guide.iter().map(|&(_, ours)| ours).collect();
+        let theirs = guide.iter().map(|&(theirs, _)| theirs).collect();
+        strategies.insert(0, Box::new(Fixed::new("guide", ours)));
+        strategies.insert(1, Box::new(Fixed::new("elves", theirs)));
+    }
+
+    let mut rows = vec![[
+        "Player",
+        "Opponent",
+        "Wins",
+        "Draws",
+        "Losses",
+        "Average",
+        "Opponent average",
+    ]
+    .map(String::from)];
+    for m in simulation::tournament(&rules, &mut strategies, rounds) {
+        rows.push([
+            m.player,
+            m.opponent,
+            m.tally.wins.to_string(),
+            m.tally.draws.to_string(),
+            m.tally.losses.to_string(),
+            format!("{:.2}", m.tally.average_score()),
+            format!("{:.2}", m.tally.opponent_average_score()),
+        ]);
+    }
+    Ok(format_table(&rows, &[0, 1]))
+}
+
+/// Formats the coverage of the sections of day 4 as tables of the summary, the gaps and the number
+/// of elves covering every section.
+fn format_coverage(coverage: &Coverage) -> String {
+    let most_covered = match coverage.most_covered() {
+        Some(segment) => format!("{} ({} elves)", segment.interval.start(), segment.count),
+        None => "none".to_string(),
+    };
+    let gaps = coverage.covered.gaps();
+    let summary = [
+        ["Assignments".to_string(), coverage.assignments.to_string()],
+        [
+            "Covered".to_string(),
+            coverage.covered.sections().to_string(),
+        ],
+        [
+            "Intervals".to_string(),
+            coverage.covered.intervals().len().to_string(),
+        ],
+        ["Gaps".to_string(), gaps.len().to_string()],
+        ["Most covered".to_string(), most_covered],
+    ];
+
+    let mut output = format_table(&summary, &[0]);
+    if !gaps.is_empty() {
+        let mut rows = vec![["Gap", "Sections"].map(String::from)];
+        for gap in gaps {
+            rows.push([gap.to_string(), gap.sections().to_string()]);
+        }
+        output.push('\n');
+        output.push_str(&format_table(&rows, &[0]));
+    }
+    let mut rows = vec![["Sections", "Elves"].map(String::from)];
+    for segment in &coverage.segments {
+        rows.push([segment.interval.to_string(), segment.count.to_string()]);
+    }
+    output.push('\n');
+    output.push_str(&format_table(&rows, &[0]));
+    output
+}
+
+/// Formats every pair of assignments of day 4 sharing a section, in the order of their lines.
+fn format_overlaps(groups: &[Group], format: Format) -> String {
+    let mut found = overlaps::find(&overlaps::assignments(groups));
+    found.sort_by_key(|overlap| (overlap.first, overlap.second));
+
+    let mut rows = vec![[
+        "Line",
+        "Elf",
+        "Sections",
+        "Other line",
+        "Other elf",
+        "Other sections",
+    ]
+    .map(String::from)];
+    for overlap in &found {
+        rows.push([
+            overlap.first.line.to_string(),
+            overlap.first.elf.to_string(),
+            overlap.first.interval.to_string(),
+            overlap.second.line.to_string(),
+            overlap.second.elf.to_string(),
+            overlap.second.interval.to_string(),
+        ]);
+    }
+
+    if format == Format::Csv {
+        rows[0] = [
+            "line",
+            "elf",
+            "sections",
+            "other_line",
+            "other_elf",
+            "other_sections",
+        ]
+        .map(String::from);
+        return format_csv(&rows);
+    }
+    format_table(&rows, &[])
+}
+
+/// Formats the statistics of day 1 as tables of the summary, the histogram and the ranks.
+fn format_statistics(statistics: &Statistics) -> String {
+    let mut summary = vec![
+        ["Elves".to_string(), statistics.elves.to_string()],
+        ["Total".to_string(), statistics.total.to_string()],
+        ["Mean".to_string(), format!("{:.2}", statistics.mean)],
+        ["Median".to_string(), format!("{:.1}", statistics.median)],
+    ];
+    for (p, calories) in &statistics.percentiles {
+        summary.push([format!("P{}", p), calories.to_string()]);
+    }
+
+    let mut histogram = vec![["Calories", "Elves", ""].map(String::from)];
+    let most = statistics
+        .histogram
+        .iter()
+        .map(|b| b.elves)
+        .max()
+        .unwrap_or(0);
+    for bucket in &statistics.histogram {
+        let bar = (bucket.elves * HISTOGRAM_WIDTH).div_ceil(most.max(1));
+        histogram.push([
+            format!("{}-{}", bucket.start, bucket.end),
+            bucket.elves.to_string(),
+            "#".repeat(bar),
+        ]);
+    }
+
+    let mut ranks = vec![["Rank", "Elf", "Items", "Calories"].map(String::from)];
+    for rank in &statistics.ranks {
+        ranks.push([
+            rank.rank.to_string(),
+            rank.index.to_string(),
+            rank.items.to_string(),
+            rank.calories.to_string(),
+        ]);
+    }
+
+    [
+        format_table(&summary, &[0]),
+        format_table(&histogram, &[0, 2]),
+        format_table(&ranks, &[]),
+    ]
+    .join("\n")
+}
+
+/// Reads standard input if it was requested, or if it is piped and the input was not given.
+///
+/// Falls back to the puzzle input when nothing is piped, e.g. when standard input is `/dev/null`.
+fn read_stdin(input: Input) -> Result<Input, String> {
+    let stdin = io::stdin();
+    let requested = match input {
+        Input::Stdin => true,
+        Input::Puzzle if !stdin.is_terminal() => false,
+        input => return Ok(input),
+    };
+
+    let mut text = String::new();
+    stdin
+        .lock()
+        .read_to_string(&mut text)
+        .map_err(|e| format!("cannot read standard input: {}", e))?;
+
+    if text.is_empty() && !requested {
+        Ok(Input::Puzzle)
+    } else {
+        Ok(Input::Piped(text))
+    }
+}
+
+/// Runs every part of every day with the default inputs and prints a summary table.
+///
+/// Days that fail are reported in the table and do not stop the others from running.
+fn run_all(locator: &Locator) -> Result<(), String> {
+    let mut rows = vec![["Day", "Part", "Answer", "Parse", "Solve"].map(String::from)];
+    let mut failed = 0;
+    let mut total = Duration::ZERO;
+
+    for (day, solver) in SOLUTIONS {
+        let run = locator
+            .read(day)
+            .and_then(|input| solver(&input, &Part::ALL));
+        match run {
+            Ok(run) => {
+                total += run.parse_time;
+                for (i, answer) in run.answers.into_iter().enumerate() {
+                    total += answer.time;
+                    let parse_time = if i == 0 {
+                        format_duration(run.parse_time)
+                    } else {
+                        String::new()
+                    };
+                    rows.push([
+                        day.to_string(),
+                        answer.part.to_string(),
+                        answer.value,
+                        parse_time,
+                        format_duration(answer.time),
+                    ]);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                rows.push([
+                    day.to_string(),
+                    "-".to_string(),
+                    format!("error: {}: {}", locator.path(day).display(), e),
+                    String::new(),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+    rows.push([
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total),
+    ]);
+
+    print!("{}", format_table(&rows, &[2]));
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} days failed", failed, SOLUTIONS.len())),
+    }
+}
+
+/// Runs every part of every day and compares the answers with the recorded ones.
+fn verify(answers: &Path, locator: &Locator) -> Result<(), String> {
+    let recorded = Answers::read(answers)
+        .map_err(|e| format!("cannot read answers {}: {}", answers.display(), e))?;
+
+    let mut rows = vec![["Day", "Part", "Status", "Answer", "Expected"].map(String::from)];
+    let mut failed = 0;
+    let mut total = 0;
+
+    for (day, solver) in SOLUTIONS {
+        let run = locator
+            .read(day)
+            .and_then(|input| solver(&input, &Part::ALL));
+        match run {
+            Ok(run) => {
+                for answer in run.answers {
+                    total += 1;
+                    let (status, expected) = match recorded.verify(day, answer.part, &answer.value)
+                    {
+                        Verdict::Pass => ("ok", String::new()),
+                        Verdict::Fail { expected } => ("FAIL", expected),
+                        Verdict::Missing => ("missing", String::new()),
+                    };
+                    if status != "ok" {
+                        failed += 1;
+                    }
+                    rows.push([
+                        day.to_string(),
+                        answer.part.to_string(),
+                        status.to_string(),
+                        answer.value,
+                        expected,
+                    ]);
+                }
+            }
+            Err(e) => {
+                total += Part::ALL.len();
+                failed += Part::ALL.len();
+                rows.push([
+                    day.to_string(),
+                    "-".to_string(),
+                    "ERROR".to_string(),
+                    format!("{}: {}", locator.path(day).display(), e),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+
+    print!("{}", format_table(&rows, &[2, 3, 4]));
+
+    match failed {
+        0 => {
+            println!("All {} answers match {}", total, answers.display());
+            Ok(())
+        }
+        _ => Err(format!("{} of {} answers do not match", failed, total)),
+    }
+}
+
+/// Scaffolds a new day in the project and prints the files created or updated.
+fn new_day(day: u32, locator: &Locator) -> Result<(), String> {
+    let project = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let paths = scaffold::create(project, day, locator)
+        .map_err(|e| format!("cannot create day {}: {}", day, e))?;
+    for path in paths {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+/// Formats a duration with two decimal places in the most fitting unit.
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// Formats rows as CSV, quoting the fields that need it.
+fn format_csv<const N: usize>(rows: &[[String; N]]) -> String {
+    let mut csv = String::new();
+    for row in rows {
+        let fields: Vec<String> = row
+            .iter()
+            .map(|field| {
+                if field.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.clone()
+                }
+            })
+            .collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Formats rows into a table with aligned columns, the first row being the header.
+///
+/// # Arguments
+///
+/// * `rows` - Rows of the table, the first one being the header.
+/// * `left` - Indices of the columns aligned to the left. Other columns are aligned to the right.
+fn format_table<const N: usize>(rows: &[[String; N]], left: &[usize]) -> String {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                if left.contains(&column) {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_run() {
+        let command = parse_args(&args("--day 5 --part 2 --input in.txt")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run {
+                day: 5,
+                part: Some(2),
+                input: Input::File(PathBuf::from("in.txt")),
+                locator: Locator::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_stdin() {
+        let command = parse_args(&args("--day 5 --input -")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run {
+                day: 5,
+                part: None,
+                input: Input::Stdin,
+                locator: Locator::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_input_dir() {
+        let command = parse_args(&args("--day 1 --example --input-dir inputs")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run {
+                day: 1,
+                part: None,
+                input: Input::Example,
+                locator: Locator::new("inputs"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_all() {
+        assert_eq!(
+            parse_args(&args("--all")).unwrap(),
+            Command::All {
+                locator: Locator::default()
+            }
+        );
+        assert!(parse_args(&args("--all --day 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_verify() {
+        let command = parse_args(&args("verify --answers a.toml")).unwrap();
+        assert_eq!(
+            command,
+            Command::Verify {
+                answers: PathBuf::from("a.toml"),
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("verify --day 1")).is_err());
+        assert!(parse_args(&args("--day 1 --answers a.toml")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_new_day() {
+        let command = parse_args(&args("new-day 8 --input-dir inputs")).unwrap();
+        assert_eq!(
+            command,
+            Command::NewDay {
+                day: 8,
+                locator: Locator::new("inputs"),
+            }
+        );
+        assert!(parse_args(&args("new-day")).is_err());
+        assert!(parse_args(&args("new-day 0")).is_err());
+        assert!(parse_args(&args("new-day 8 --day 8")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_report() {
+        let command = parse_args(&args("--day 1 --stats --json --example")).unwrap();
+        assert_eq!(
+            command,
+            Command::Report {
+                day: 1,
+                report: Report::Stats,
+                format: Format::Json,
+                input: Input::Example,
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("--day 2 --stats")).is_err());
+        assert!(parse_args(&args("--day 1 --part 1 --stats")).is_err());
+        assert!(parse_args(&args("--day 1 --json")).is_err());
+
+        let command = parse_args(&args("--day 1 --stream --top 5")).unwrap();
+        assert_eq!(
+            command,
+            Command::Stream {
+                top: 5,
+                format: Format::Table,
+                input: Input::Puzzle,
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("--day 1 --stats --top 5")).is_err());
+        for flags in ["--stream --stats", "--stats --stream", "--check --stream"] {
+            let error = parse_args(&args(&format!("--day 1 {}", flags))).unwrap_err();
+            assert!(error.starts_with("--stream cannot be combined with --"));
+        }
+        assert!(parse_args(&args("--day 1 --stream --csv")).is_err());
+        assert!(parse_args(&args("--day 2 --stream")).is_err());
+        assert!(parse_args(&args("--day 2 --encodings")).is_ok());
+        assert!(parse_args(&args("--day 2 --encodings --json")).is_err());
+
+        let command = parse_args(&args("--day 2 --seed 42 --simulate")).unwrap();
+        assert_eq!(
+            command,
+            Command::Report {
+                day: 2,
+                report: Report::Simulate {
+                    rounds: 1000,
+                    seed: 42
+                },
+                format: Format::Table,
+                input: Input::Puzzle,
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("--day 2 --rounds 5")).is_err());
+
+        let command = parse_args(&args("--day 2 --explain --part 2 --csv")).unwrap();
+        assert_eq!(
+            command,
+            Command::Report {
+                day: 2,
+                report: Report::Explain { part: 2 },
+                format: Format::Csv,
+                input: Input::Puzzle,
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("--day 2 --explain --json")).is_err());
+        assert!(parse_args(&args("--day 1 --stats --csv")).is_err());
+
+        let command = parse_args(&args("--day 3 --check --csv --example")).unwrap();
+        assert_eq!(
+            command,
+            Command::Report {
+                day: 3,
+                report: Report::Check,
+                format: Format::Csv,
+                input: Input::Example,
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("--day 2 --check")).is_err());
+        assert!(parse_args(&args("--day 3 --check --json")).is_err());
+
+        let command = parse_args(&args("--day 3 --priorities --part 2 --alphabet abc")).unwrap();
+        assert_eq!(
+            command,
+            Command::Report {
+                day: 3,
+                report: Report::Priorities {
+                    part: 2,
+                    scheme: Scheme::Alphabet("abc".to_string())
+                },
+                format: Format::Table,
+                input: Input::Puzzle,
+                locator: Locator::default(),
+            }
+        );
+        assert!(parse_args(&args("--day 3 --check --weights w.txt")).is_err());
+        assert!(parse_args(&args("--day 3 --priorities --part 3")).is_err());
+        assert!(parse_args(&args("--day 4 --coverage --json")).is_ok());
+        assert!(parse_args(&args("--day 4 --coverage --csv")).is_err());
+        assert!(parse_args(&args("--day 4 --overlaps --csv")).is_ok());
+        assert!(parse_args(&args("--day 4 --overlaps --json")).is_err());
+        assert!(parse_args(&args("verify --top 5")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("--day")).is_err());
+        assert!(parse_args(&args("--day five")).is_err());
+        assert!(parse_args(&args("--frobnicate")).is_err());
+    }
+
+    #[test]
+    fn test_run_unknown_day_and_part() {
+        let locator = Locator::default();
+        assert_eq!(
+            run(99, None, &Input::Puzzle, &locator),
+            Err("unknown day: 99".to_string())
+        );
+        assert_eq!(
+            run(1, Some(3), &Input::Puzzle, &locator),
+            Err("unknown part: 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_csv() {
+        let rows = [
+            ["name", "value"].map(String::from),
+            ["a,b", "say \"hi\""].map(String::from),
+        ];
+        assert_eq!(
+            format_csv(&rows),
+            "name,value\n\"a,b\",\"say \"\"hi\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_format_table() {
+        let rows = [
+            ["Day", "Part", "Answer", "Parse", "Solve"].map(String::from),
+            ["5", "1", "CMZ", "12.00µs", "1.50µs"].map(String::from),
+            ["10", "2", "", "", "3.00ms"].map(String::from),
+        ];
+        assert_eq!(
+            format_table(&rows, &[2]),
+            "Day  Part  Answer    Parse   Solve
+  5     1  CMZ     12.00µs  1.50µs
+ 10     2                   3.00ms
+"
+        );
+    }
 }
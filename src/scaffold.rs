@@ -0,0 +1,300 @@
+//! Scaffolding of new days: the module, its registration and empty input files.
+
+use crate::input::Locator;
+use crate::Result;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Module of a new day, with `{day}` standing for its number.
+const TEMPLATE: &str = r#"//! Day {day} of Advent of Code 2022
+
+use crate::{Result, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part1(_lines: &Vec<String>) -> usize {
+        0
+    }
+
+    fn part2(_lines: &Vec<String>) -> usize {
+        0
+    }
+}
+
+/// Returns the answer to part 1.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day{day};
+///
+/// let input = "";
+/// assert_eq!(day{day}::part_1(input).unwrap(), 0);
+/// ```
+pub fn part_1(input: &str) -> Result<usize> {
+    Ok(Day{day}::part1(&parse_input(input)?))
+}
+
+/// Returns the answer to part 2.
+///
+/// # Arguments
+///
+/// * `input` - Puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code_2022::day{day};
+///
+/// let input = "";
+/// assert_eq!(day{day}::part_2(input).unwrap(), 0);
+/// ```
+pub fn part_2(input: &str) -> Result<usize> {
+    Ok(Day{day}::part2(&parse_input(input)?))
+}
+
+/// Parses every line of the input.
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_part_1() {
+        let input = input::read_example({day}).unwrap();
+        assert_eq!(part_1(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = input::read_example({day}).unwrap();
+        assert_eq!(part_2(&input).unwrap(), 0);
+    }
+}
+"#;
+
+/// Start of the declaration of the solutions array in `lib.rs`.
+const SOLUTIONS_DECLARATION: &str = "pub const SOLUTIONS: [(u32, Solver); ";
+
+/// Returns the source of the module of a new day.
+pub fn module(day: u32) -> String {
+    TEMPLATE.replace("{day}", &day.to_string())
+}
+
+/// Scaffolds a new day in the project and returns the paths of the files created or updated.
+///
+/// Writes `src/dayN.rs`, registers the module and its solution in `src/lib.rs`, adds it to the
+/// benchmarks and creates empty puzzle and example inputs in the input directory. Nothing is
+/// written if the module already exists or the project files cannot be updated.
+///
+/// # Arguments
+///
+/// * `project` - Directory with `Cargo.toml` of the project.
+/// * `day` - Number of the new day.
+/// * `locator` - Locator of the input files.
+pub fn create(project: &Path, day: u32, locator: &Locator) -> Result<Vec<PathBuf>> {
+    let module_path = project.join("src").join(format!("day{}.rs", day));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        )
+        .into());
+    }
+
+    let lib_path = project.join("src").join("lib.rs");
+    let lib = register_module(&fs::read_to_string(&lib_path)?, day)
+        .ok_or_else(|| unexpected_layout(&lib_path))?;
+    let bench_path = project.join("benches").join("days.rs");
+    let bench = register_bench(&fs::read_to_string(&bench_path)?, day)
+        .ok_or_else(|| unexpected_layout(&bench_path))?;
+
+    fs::write(&module_path, module(day))?;
+    fs::write(&lib_path, lib)?;
+    fs::write(&bench_path, bench)?;
+    let mut paths = vec![module_path, lib_path, bench_path];
+
+    fs::create_dir_all(locator.dir())?;
+    for path in [locator.path(day), locator.example_path(day)] {
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => paths.push(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Returns the error of a project file that cannot be updated.
+fn unexpected_layout(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "cannot find where to register the day in {}",
+            path.display()
+        ),
+    )
+}
+
+/// Declares the module of a day in `lib.rs` and adds its solution to `SOLUTIONS`.
+///
+/// Returns `None` if the day is already registered or the declarations are not found.
+fn register_module(lib: &str, day: u32) -> Option<String> {
+    let lib = insert_line(
+        lib,
+        day,
+        ("pub mod day", ";"),
+        &format!("pub mod day{};", day),
+    )?;
+    let lib = insert_line(
+        &lib,
+        day,
+        ("(", ", solve::<"),
+        &format!("({}, solve::<day{}::Day{}>),", day, day, day),
+    )?;
+
+    let start = lib.find(SOLUTIONS_DECLARATION)? + SOLUTIONS_DECLARATION.len();
+    let end = start + lib[start..].find(']')?;
+    let count = lib[start..end].parse::<usize>().ok()?;
+    Some(format!("{}{}{}", &lib[..start], count + 1, &lib[end..]))
+}
+
+/// Adds the benchmarks of a day to `benches/days.rs`.
+///
+/// Returns `None` if the day is already benchmarked or the other days are not found.
+fn register_bench(bench: &str, day: u32) -> Option<String> {
+    insert_line(
+        bench,
+        day,
+        ("harness.day::<aoc::day", "::Day"),
+        &format!("harness.day::<aoc::day{}::Day{}>({});", day, day, day),
+    )
+}
+
+/// Inserts a line among the lines registering other days, keeping them ordered by day.
+///
+/// # Arguments
+///
+/// * `text` - Text with the lines registering days.
+/// * `day` - Day of the new line.
+/// * `pattern` - Text before and after the day in the lines registering days.
+/// * `line` - New line, indented like its neighbours.
+fn insert_line(text: &str, day: u32, pattern: (&str, &str), line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let entries: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line_day(line, pattern)?)))
+        .collect();
+    if entries.iter().any(|(_, other)| *other == day) {
+        return None;
+    }
+
+    let (&(last, _), _) = entries.split_last()?;
+    let (position, neighbour) = match entries.iter().find(|(_, other)| *other > day) {
+        Some(&(i, _)) => (i, lines[i]),
+        None => (last + 1, lines[last]),
+    };
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+
+    let mut result: String = lines[..position].concat();
+    result.push_str(indent);
+    result.push_str(line);
+    result.push('\n');
+    result.push_str(&lines[position..].concat());
+    Some(result)
+}
+
+/// Returns the day of a line like `pub mod day7;` matching the text before and after the day.
+fn line_day(line: &str, (prefix, suffix): (&str, &str)) -> Option<u32> {
+    let rest = line.trim().strip_prefix(prefix)?;
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    if !rest[digits..].starts_with(suffix) {
+        return None;
+    }
+    rest[..digits].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod day1;
+pub mod day3;
+
+pub const SOLUTIONS: [(u32, Solver); 2] = [
+    (1, solve::<day1::Day1>),
+    (3, solve::<day3::Day3>),
+];
+";
+
+    #[test]
+    fn test_module() {
+        let module = module(8);
+        assert!(module.starts_with("//! Day 8 of Advent of Code 2022\n"));
+        assert!(module.contains("impl Solution for Day8 {"));
+        assert!(module.contains("input::read_example(8)"));
+        assert!(!module.contains("{day}"));
+    }
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module(LIB, 4).unwrap(),
+            "pub mod day1;
+pub mod day3;
+pub mod day4;
+
+pub const SOLUTIONS: [(u32, Solver); 3] = [
+    (1, solve::<day1::Day1>),
+    (3, solve::<day3::Day3>),
+    (4, solve::<day4::Day4>),
+];
+"
+        );
+        assert_eq!(
+            register_module(LIB, 2).unwrap(),
+            "pub mod day1;
+pub mod day2;
+pub mod day3;
+
+pub const SOLUTIONS: [(u32, Solver); 3] = [
+    (1, solve::<day1::Day1>),
+    (2, solve::<day2::Day2>),
+    (3, solve::<day3::Day3>),
+];
+"
+        );
+        assert_eq!(register_module(LIB, 3), None);
+        assert_eq!(register_module("", 3), None);
+    }
+
+    #[test]
+    fn test_register_project() {
+        let lib = register_module(include_str!("lib.rs"), 99).unwrap();
+        assert!(lib.contains("pub mod day99;\n"));
+        assert!(lib.contains("    (99, solve::<day99::Day99>),\n];"));
+
+        let bench = register_bench(include_str!("../benches/days.rs"), 99).unwrap();
+        assert!(bench.contains("    harness.day::<aoc::day99::Day99>(99);\n}"));
+    }
+}